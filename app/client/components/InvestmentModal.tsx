@@ -163,7 +163,7 @@ export const InvestModal = ({
         provider,
         program,
         property,
-        usdcAmount,
+        Math.floor(usdcAmount / tokenPriceUSDC),
         wallet,
       );
 
@@ -7,7 +7,10 @@ import { useAnchor } from "@/hooks/use-anchor";
 import { SendTransactionError } from "@solana/web3.js";
 import { toast } from "@/hooks/use-toast";
 import { Investment, Property } from "@/utils/solana";
-import { withdrawInvestment } from "@/services/program";
+import {
+  redeemDividendsTransaction,
+  withdrawInvestment,
+} from "@/services/program";
 import { getUsdcBalance } from "@/services/usdc";
 import {
   Dialog,
@@ -86,6 +89,7 @@ export const ManageInvestmentModal = ({
         program,
         investment,
         propertyData,
+        investment.amount,
         wallet,
       );
 
@@ -133,11 +137,10 @@ export const ManageInvestmentModal = ({
     try {
       setIsSubmitting(true);
 
-      const { txSignature } = await withdrawInvestment(
+      const txSignature = await redeemDividendsTransaction(
         provider,
         program,
         investment,
-        propertyData,
         wallet,
       );
 
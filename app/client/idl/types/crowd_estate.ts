@@ -14,47 +14,209 @@ export type CrowdEstate = {
   },
   "instructions": [
     {
-      "name": "closeProperty",
+      "name": "acceptAdmin",
       "discriminator": [
-        221,
-        217,
-        65,
-        122,
-        187,
-        119,
-        89,
-        243
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
       ],
       "accounts": [
+        {
+          "name": "newAdmin",
+          "signer": true
+        },
         {
           "name": "property",
           "writable": true
         },
         {
-          "name": "admin",
+          "name": "roles",
           "writable": true,
-          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addLiquidity",
+      "discriminator": [
+        181,
+        157,
+        89,
+        67,
+        143,
+        182,
+        52,
+        72
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property",
           "relations": [
-            "property"
+            "pool"
           ]
         },
         {
-          "name": "adminUsdcAccount",
+          "name": "propertyMint"
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseVault",
           "writable": true
         },
         {
-          "name": "propertyMint",
+          "name": "quoteVault",
           "writable": true
         },
         {
-          "name": "propertyVault",
+          "name": "providerInvestmentAccount",
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "providerComplianceRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
               {
                 "kind": "account",
                 "path": "property"
               },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lpPosition",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  112,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "providerPropertyTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "provider"
+              },
               {
                 "kind": "const",
                 "value": [
@@ -136,6 +298,10 @@ export type CrowdEstate = {
             }
           }
         },
+        {
+          "name": "providerUsdcAccount",
+          "writable": true
+        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -145,67 +311,121 @@ export type CrowdEstate = {
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "baseAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxQuoteAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minShares",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "createProperty",
+      "name": "approveCouncilAction",
       "discriminator": [
-        45,
-        115,
         89,
-        113,
-        193,
         252,
-        125,
-        27
+        158,
+        111,
+        7,
+        111,
+        245,
+        247
       ],
       "accounts": [
         {
-          "name": "admin",
-          "writable": true,
+          "name": "member",
+          "signer": true
+        },
+        {
+          "name": "council",
+          "relations": [
+            "councilAction"
+          ]
+        },
+        {
+          "name": "councilAction",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelListing",
+      "discriminator": [
+        41,
+        183,
+        50,
+        232,
+        230,
+        233,
+        157,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "payer",
           "signer": true
         },
+        {
+          "name": "seller",
+          "writable": true,
+          "relations": [
+            "listing"
+          ]
+        },
         {
           "name": "property",
           "writable": true,
+          "relations": [
+            "listing"
+          ]
+        },
+        {
+          "name": "propertyMint"
+        },
+        {
+          "name": "listing",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  112,
-                  101,
-                  114,
+                  108,
+                  105,
+                  115,
                   116,
-                  121
+                  105,
+                  110,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "admin"
+                "path": "property"
               },
               {
-                "kind": "arg",
-                "path": "propertyName"
+                "kind": "account",
+                "path": "seller"
               }
             ]
           }
         },
         {
-          "name": "propertyMint",
-          "writable": true
-        },
-        {
-          "name": "propertyVault",
+          "name": "listingVault",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "property"
+                "path": "listing"
               },
               {
                 "kind": "const",
@@ -289,75 +509,28 @@ export type CrowdEstate = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        }
-      ],
-      "args": [
-        {
-          "name": "propertyName",
-          "type": "string"
-        },
-        {
-          "name": "totalTokens",
-          "type": "u64"
-        },
-        {
-          "name": "tokenPriceUsdc",
-          "type": "u64"
-        },
-        {
-          "name": "tokenSymbol",
-          "type": "string"
-        },
-        {
-          "name": "bump",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "createProposal",
-      "discriminator": [
-        132,
-        116,
-        68,
-        174,
-        216,
-        160,
-        198,
-        22
-      ],
-      "accounts": [
-        {
-          "name": "proposal",
+          "name": "sellerInvestmentAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  112,
-                  111,
+                  105,
+                  110,
+                  118,
+                  101,
                   115,
-                  97,
-                  108
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "proposer"
+                "path": "seller"
               },
               {
                 "kind": "account",
@@ -367,128 +540,13 @@ export type CrowdEstate = {
           }
         },
         {
-          "name": "proposer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "property",
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "description",
-          "type": "string"
-        },
-        {
-          "name": "proposalType",
-          "type": {
-            "defined": {
-              "name": "proposalType"
-            }
-          }
-        },
-        {
-          "name": "newAdmin",
-          "type": "string"
-        },
-        {
-          "name": "additionalTokens",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "distributeDividends",
-      "discriminator": [
-        185,
-        147,
-        6,
-        245,
-        80,
-        98,
-        186,
-        136
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "adminUsdcAccount",
-          "writable": true
-        },
-        {
-          "name": "property",
-          "writable": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "totalDividends",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "executeProposal",
-      "discriminator": [
-        186,
-        60,
-        116,
-        133,
-        108,
-        128,
-        111,
-        28
-      ],
-      "accounts": [
-        {
-          "name": "proposal",
-          "writable": true
-        },
-        {
-          "name": "admin",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "newAdmin",
-          "writable": true,
-          "signer": true,
-          "optional": true
-        },
-        {
-          "name": "property",
-          "writable": true
-        },
-        {
-          "name": "propertyMint",
-          "writable": true
-        },
-        {
-          "name": "propertyVault",
+          "name": "sellerPropertyTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "property"
+                "path": "seller"
               },
               {
                 "kind": "const",
@@ -571,10 +629,6 @@ export type CrowdEstate = {
             }
           }
         },
-        {
-          "name": "destinationTokenAccount",
-          "writable": true
-        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -587,30 +641,73 @@ export type CrowdEstate = {
       "args": []
     },
     {
-      "name": "investInProperty",
+      "name": "cancelOrder",
       "discriminator": [
-        0,
-        26,
-        127,
-        54,
-        185,
-        102,
-        118,
-        203
+        95,
+        129,
+        237,
+        240,
+        8,
+        49,
+        223,
+        132
       ],
       "accounts": [
         {
-          "name": "investor",
-          "writable": true,
+          "name": "payer",
           "signer": true
         },
         {
-          "name": "investorUsdcAccount",
+          "name": "owner"
+        },
+        {
+          "name": "property",
+          "relations": [
+            "orderBook"
+          ]
+        },
+        {
+          "name": "propertyMint"
+        },
+        {
+          "name": "orderBook",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  98,
+                  111,
+                  111,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseVault",
           "writable": true
         },
         {
-          "name": "investmentAccount",
+          "name": "quoteVault",
+          "writable": true
+        },
+        {
+          "name": "ownerInvestmentAccount",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -630,7 +727,7 @@ export type CrowdEstate = {
               },
               {
                 "kind": "account",
-                "path": "investor"
+                "path": "owner"
               },
               {
                 "kind": "account",
@@ -640,25 +737,13 @@ export type CrowdEstate = {
           }
         },
         {
-          "name": "property",
-          "writable": true
-        },
-        {
-          "name": "propertyMint",
-          "writable": true
-        },
-        {
-          "name": "propertyUsdcAccount",
-          "writable": true
-        },
-        {
-          "name": "propertyVault",
+          "name": "ownerPropertyTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "property"
+                "path": "owner"
               },
               {
                 "kind": "const",
@@ -742,28 +827,241 @@ export type CrowdEstate = {
           }
         },
         {
-          "name": "investorPropertyTokenAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "investor"
-              },
-              {
-                "kind": "const",
-                "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
+          "name": "ownerUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "orderId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelProposal",
+      "discriminator": [
+        106,
+        74,
+        128,
+        146,
+        19,
+        65,
+        39,
+        23
+      ],
+      "accounts": [
+        {
+          "name": "proposer",
+          "signer": true,
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changeVote",
+      "discriminator": [
+        184,
+        39,
+        97,
+        137,
+        83,
+        108,
+        185,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "relations": [
+            "voteRecord"
+          ]
+        },
+        {
+          "name": "voteRecord",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "vote",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "claimCommitment",
+      "discriminator": [
+        170,
+        102,
+        241,
+        51,
+        54,
+        29,
+        2,
+        46
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "investor",
+          "writable": true,
+          "relations": [
+            "commitment"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true,
+          "relations": [
+            "commitment"
+          ]
+        },
+        {
+          "name": "commitmentRound",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  114,
+                  111,
+                  117,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "commitment",
+          "writable": true
+        },
+        {
+          "name": "investmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "complianceRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "propertyMint",
+          "writable": true
+        },
+        {
+          "name": "propertyVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
                   70,
                   206,
                   235,
@@ -832,49 +1130,10 @@ export type CrowdEstate = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "usdcAmount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "mintAdditionalTokens",
-      "discriminator": [
-        145,
-        232,
-        211,
-        225,
-        5,
-        19,
-        26,
-        15
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "property",
-          "writable": true
-        },
-        {
-          "name": "propertyMint",
-          "writable": true
+          "name": "usdcMint"
         },
         {
-          "name": "propertyVault",
+          "name": "propertyUsdcAccount",
           "writable": true,
           "pda": {
             "seeds": [
@@ -921,7 +1180,7 @@ export type CrowdEstate = {
               },
               {
                 "kind": "account",
-                "path": "propertyMint"
+                "path": "usdcMint"
               }
             ],
             "program": {
@@ -964,92 +1223,13 @@ export type CrowdEstate = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "redeemDividends",
-      "discriminator": [
-        12,
-        163,
-        219,
-        221,
-        178,
-        141,
-        193,
-        63
-      ],
-      "accounts": [
-        {
-          "name": "investor",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "propertyUsdcAccount",
-          "writable": true
-        },
-        {
-          "name": "investorUsdcAccount",
-          "writable": true
-        },
-        {
-          "name": "property",
-          "writable": true
-        },
-        {
-          "name": "investmentAccount",
-          "writable": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "transferTokens",
-      "discriminator": [
-        54,
-        180,
-        238,
-        175,
-        74,
-        85,
-        126,
-        188
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "fromTokenAccount",
+          "name": "investorPropertyTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "investor"
               },
               {
                 "kind": "const",
@@ -1133,13 +1313,13 @@ export type CrowdEstate = {
           }
         },
         {
-          "name": "toTokenAccount",
+          "name": "investorUsdcAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "to"
+                "path": "investor"
               },
               {
                 "kind": "const",
@@ -1180,7 +1360,7 @@ export type CrowdEstate = {
               },
               {
                 "kind": "account",
-                "path": "propertyMint"
+                "path": "usdcMint"
               }
             ],
             "program": {
@@ -1222,251 +1402,298 @@ export type CrowdEstate = {
             }
           }
         },
-        {
-          "name": "to"
-        },
-        {
-          "name": "propertyMint",
-          "writable": true
-        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "updateProperty",
+      "name": "claimPoolDividends",
       "discriminator": [
-        232,
-        71,
+        120,
+        79,
+        68,
+        174,
         59,
-        188,
-        98,
-        74,
-        94,
-        54
+        8,
+        0,
+        184
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "owner",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "lpPosition"
+          ]
         },
         {
           "name": "property",
-          "writable": true
+          "relations": [
+            "pool"
+          ]
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          },
+          "relations": [
+            "lpPosition"
+          ]
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        }
-      ],
-      "args": [
-        {
-          "name": "tokenSymbol",
-          "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "voteOnProposal",
-      "discriminator": [
-        188,
-        239,
-        13,
-        88,
-        119,
-        199,
-        251,
-        119
-      ],
-      "accounts": [
-        {
-          "name": "proposal",
+          "name": "lpPosition",
           "writable": true
         },
         {
-          "name": "voteRecord",
+          "name": "ownerInvestmentAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  105,
+                  110,
                   118,
-                  111,
+                  101,
+                  115,
                   116,
-                  101
+                  109,
+                  101,
+                  110,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "proposal"
+                "path": "owner"
               },
               {
                 "kind": "account",
-                "path": "voter"
+                "path": "property"
               }
             ]
           }
         },
         {
-          "name": "voter",
+          "name": "ownerComplianceRecord",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "closeAuction",
+      "discriminator": [
+        225,
+        129,
+        91,
+        48,
+        215,
+        73,
+        203,
+        172
+      ],
+      "accounts": [
         {
-          "name": "vote",
-          "type": "bool"
+          "name": "property",
+          "writable": true
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "withdrawInvestment",
+      "name": "closeProperty",
       "discriminator": [
-        157,
-        158,
-        101,
-        11,
-        240,
-        193,
-        192,
-        92
+        221,
+        217,
+        65,
+        122,
+        187,
+        119,
+        89,
+        243
       ],
       "accounts": [
         {
-          "name": "investor",
-          "writable": true,
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "operator",
           "signer": true,
           "relations": [
-            "investmentAccount"
+            "roles"
           ]
         },
         {
-          "name": "investorUsdcAccount",
-          "writable": true
-        },
-        {
-          "name": "investmentAccount",
-          "writable": true
+          "name": "admin",
+          "writable": true,
+          "relations": [
+            "property"
+          ]
         },
         {
-          "name": "investorPropertyTokenAccount",
-          "writable": true,
+          "name": "roles",
           "pda": {
             "seeds": [
               {
-                "kind": "account",
-                "path": "investor"
-              },
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "orderBook",
+          "pda": {
+            "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  111,
+                  114,
+                  100,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  114,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  98,
+                  111,
+                  111,
+                  107
                 ]
               },
               {
                 "kind": "account",
-                "path": "propertyMint"
+                "path": "property"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "property",
-          "writable": true,
-          "relations": [
-            "investmentAccount"
-          ]
+          "name": "pool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "commitmentRound",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  114,
+                  111,
+                  117,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminUsdcAccount",
+          "writable": true
         },
         {
           "name": "propertyMint",
@@ -1562,14 +1789,6 @@ export type CrowdEstate = {
             }
           }
         },
-        {
-          "name": "propertyUsdcAccount",
-          "writable": true
-        },
-        {
-          "name": "adminUsdcAccount",
-          "writable": true
-        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -1580,289 +1799,8740 @@ export type CrowdEstate = {
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "investor",
-      "discriminator": [
-        174,
-        129,
-        17,
-        83,
-        36,
-        116,
-        26,
-        196
-      ]
     },
     {
-      "name": "property",
+      "name": "closeProposal",
       "discriminator": [
-        195,
-        247,
-        69,
-        181,
-        195,
-        47,
-        152,
-        19
-      ]
+        213,
+        178,
+        139,
+        19,
+        50,
+        191,
+        82,
+        245
+      ],
+      "accounts": [
+        {
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        },
+        {
+          "name": "investmentAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposer"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposerDepositAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "propertyDepositAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "proposal",
+      "name": "commitToRound",
       "discriminator": [
-        26,
-        94,
-        189,
-        187,
-        116,
-        136,
-        53,
-        33
-      ]
-    },
+        49,
+        240,
+        42,
+        87,
+        185,
+        100,
+        196,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "commitmentRound",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  114,
+                  111,
+                  117,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "commitment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "complianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "propertyUsdcAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokens",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "configureDutchAuction",
+      "discriminator": [
+        114,
+        31,
+        141,
+        223,
+        96,
+        144,
+        131,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "property"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "usdcMint"
+        }
+      ],
+      "args": [
+        {
+          "name": "startPrice",
+          "type": "u64"
+        },
+        {
+          "name": "floorPrice",
+          "type": "u64"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
+        },
+        {
+          "name": "duration",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "createAdminCouncil",
+      "discriminator": [
+        8,
+        56,
+        180,
+        46,
+        214,
+        176,
+        36,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "property"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "council",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "roles",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "members",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createListing",
+      "discriminator": [
+        18,
+        168,
+        45,
+        24,
+        191,
+        31,
+        117,
+        54
+      ],
+      "accounts": [
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "propertyMint"
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "sellerInvestmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sellerComplianceRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sellerPropertyTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "listingVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "listing"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokens",
+          "type": "u64"
+        },
+        {
+          "name": "pricePerTokenUsdc",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createProperty",
+      "discriminator": [
+        45,
+        115,
+        89,
+        113,
+        193,
+        252,
+        125,
+        27
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  101,
+                  114,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "arg",
+                "path": "propertyName"
+              }
+            ]
+          }
+        },
+        {
+          "name": "roles",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "propertyMint",
+          "writable": true
+        },
+        {
+          "name": "propertyVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "usdcMint",
+          "docs": [
+            "The stablecoin the property is bought, redeemed and paid out in."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "propertyName",
+          "type": "string"
+        },
+        {
+          "name": "totalTokens",
+          "type": "u64"
+        },
+        {
+          "name": "tokenPriceUsdc",
+          "type": "u64"
+        },
+        {
+          "name": "tokenSymbol",
+          "type": "string"
+        },
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createProposal",
+      "discriminator": [
+        132,
+        116,
+        68,
+        174,
+        216,
+        160,
+        198,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "governance",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "governance.proposalCount",
+                "account": "Governance"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "investmentAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposer"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposerDepositAccount",
+          "docs": [
+            "The proposer's USDC or property token account, matching the",
+            "property's deposit asset."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "propertyDepositAccount",
+          "docs": [
+            "The property's USDC associated token account or its vault, whichever",
+            "matches the deposit asset."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "description",
+          "type": "string"
+        },
+        {
+          "name": "payload",
+          "type": {
+            "defined": {
+              "name": "proposalPayload"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "delegateVotes",
+      "discriminator": [
+        134,
+        214,
+        180,
+        254,
+        134,
+        143,
+        185,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "delegator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property"
+        },
+        {
+          "name": "delegation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "delegator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "distributeDividends",
+      "discriminator": [
+        185,
+        147,
+        6,
+        245,
+        80,
+        98,
+        186,
+        136
+      ],
+      "accounts": [
+        {
+          "name": "treasurer",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "roles"
+          ]
+        },
+        {
+          "name": "treasurerUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "roles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "totalDividends",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "executeCouncilAction",
+      "discriminator": [
+        138,
+        236,
+        242,
+        200,
+        98,
+        153,
+        212,
+        82
+      ],
+      "accounts": [
+        {
+          "name": "member",
+          "signer": true
+        },
+        {
+          "name": "council",
+          "relations": [
+            "councilAction"
+          ]
+        },
+        {
+          "name": "councilAction",
+          "writable": true
+        },
+        {
+          "name": "crowdEstateProgram",
+          "address": "rwa5WajX9npiz1iHAHYQ9AwGcKAM9Ru8DkrRkfGjN9d"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeProposal",
+      "discriminator": [
+        186,
+        60,
+        116,
+        133,
+        108,
+        128,
+        111,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "proposal.index",
+                "account": "Proposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "propertyMint",
+          "writable": true
+        },
+        {
+          "name": "propertyVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "destinationTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "propertyUsdcAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "recipientUsdcAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fillListing",
+      "discriminator": [
+        207,
+        127,
+        12,
+        109,
+        233,
+        168,
+        239,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "relations": [
+            "listing"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true,
+          "relations": [
+            "listing"
+          ]
+        },
+        {
+          "name": "propertyMint"
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "listingVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "listing"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "sellerInvestmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sellerComplianceRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyerInvestmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyerComplianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sellerUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "buyerUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "buyerPropertyTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokens",
+          "type": "u64"
+        },
+        {
+          "name": "maxPricePerTokenUsdc",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "finalizeCommitmentRound",
+      "discriminator": [
+        65,
+        200,
+        224,
+        165,
+        146,
+        96,
+        22,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "commitmentRound",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  114,
+                  111,
+                  117,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeProposal",
+      "discriminator": [
+        23,
+        68,
+        51,
+        167,
+        109,
+        173,
+        187,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "property",
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeOrderBook",
+      "discriminator": [
+        93,
+        233,
+        9,
+        128,
+        33,
+        199,
+        152,
+        88
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "property"
+          ]
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property"
+        },
+        {
+          "name": "propertyMint"
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "orderBook",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  98,
+                  111,
+                  111,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "orderBook"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "quoteVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "orderBook"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializePool",
+      "discriminator": [
+        95,
+        180,
+        10,
+        172,
+        84,
+        174,
+        232,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "property"
+          ]
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property"
+        },
+        {
+          "name": "propertyMint"
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "pool"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "quoteVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "pool"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "feeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "investInProperty",
+      "discriminator": [
+        0,
+        26,
+        127,
+        54,
+        185,
+        102,
+        118,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "investorUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "investmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "complianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investmentRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "property.investmentCount",
+                "account": "Property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "propertyMint",
+          "writable": true
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "propertyUsdcAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "propertyVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorPropertyTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "platformFeeAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referrerStats",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referrerUsdcAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokensToPurchase",
+          "type": "u64"
+        },
+        {
+          "name": "maxTokenPriceUsdc",
+          "type": "u64"
+        },
+        {
+          "name": "expirySlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintAdditionalTokens",
+      "discriminator": [
+        145,
+        232,
+        211,
+        225,
+        5,
+        19,
+        26,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "treasurer",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "roles"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "roles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "propertyMint",
+          "writable": true
+        },
+        {
+          "name": "propertyVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "openCommitmentRound",
+      "discriminator": [
+        73,
+        155,
+        204,
+        113,
+        160,
+        47,
+        216,
+        83
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "property"
+          ]
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "commitmentRound",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  114,
+                  111,
+                  117,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "endTime",
+          "type": "i64"
+        },
+        {
+          "name": "priorityList",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "placeAuctionBid",
+      "discriminator": [
+        38,
+        170,
+        233,
+        87,
+        52,
+        65,
+        23,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "auctionBid",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  98,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "bidder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "complianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "bidder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bidderUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "propertyUsdcAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokens",
+          "type": "u64"
+        },
+        {
+          "name": "maxPriceUsdc",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "placeOrder",
+      "discriminator": [
+        51,
+        194,
+        155,
+        175,
+        109,
+        130,
+        96,
+        106
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property",
+          "relations": [
+            "orderBook"
+          ]
+        },
+        {
+          "name": "propertyMint"
+        },
+        {
+          "name": "orderBook",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  98,
+                  111,
+                  111,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseVault",
+          "writable": true
+        },
+        {
+          "name": "quoteVault",
+          "writable": true
+        },
+        {
+          "name": "ownerInvestmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ownerComplianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ownerPropertyTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "ownerUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "side",
+          "type": {
+            "defined": {
+              "name": "orderSide"
+            }
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "quantity",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "proposeCouncilAction",
+      "discriminator": [
+        70,
+        182,
+        159,
+        134,
+        3,
+        111,
+        42,
+        141
+      ],
+      "accounts": [
+        {
+          "name": "member",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "council",
+          "writable": true
+        },
+        {
+          "name": "councilAction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108,
+                  95,
+                  97,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "council"
+              },
+              {
+                "kind": "account",
+                "path": "council.actionCount",
+                "account": "AdminCouncil"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "action",
+          "type": {
+            "defined": {
+              "name": "councilActionKind"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeemDividends",
+      "discriminator": [
+        12,
+        163,
+        219,
+        221,
+        178,
+        141,
+        193,
+        63
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "investmentAccount"
+          ]
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "propertyUsdcAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "taxVault",
+          "writable": true
+        },
+        {
+          "name": "property",
+          "writable": true,
+          "relations": [
+            "investmentAccount"
+          ]
+        },
+        {
+          "name": "investmentAccount",
+          "writable": true
+        },
+        {
+          "name": "complianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "taxRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  120,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "investmentAccount"
+              },
+              {
+                "kind": "arg",
+                "path": "year"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "year",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "registerReferrer",
+      "discriminator": [
+        122,
+        229,
+        215,
+        169,
+        100,
+        145,
+        198,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "referrer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "referrerStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "referrer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerUsdcAccount"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeLiquidity",
+      "discriminator": [
+        80,
+        85,
+        209,
+        72,
+        24,
+        206,
+        177,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "provider"
+        },
+        {
+          "name": "property",
+          "relations": [
+            "pool"
+          ]
+        },
+        {
+          "name": "propertyMint"
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          },
+          "relations": [
+            "lpPosition"
+          ]
+        },
+        {
+          "name": "baseVault",
+          "writable": true
+        },
+        {
+          "name": "quoteVault",
+          "writable": true
+        },
+        {
+          "name": "providerInvestmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "providerComplianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lpPosition",
+          "writable": true
+        },
+        {
+          "name": "providerPropertyTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "providerUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "shares",
+          "type": "u64"
+        },
+        {
+          "name": "minBaseAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minQuoteAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "retractVote",
+      "discriminator": [
+        227,
+        0,
+        85,
+        234,
+        243,
+        42,
+        133,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "relations": [
+            "voteRecord"
+          ]
+        },
+        {
+          "name": "voteRecord",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeDelegation",
+      "discriminator": [
+        188,
+        92,
+        135,
+        67,
+        160,
+        181,
+        54,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "delegator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "delegation"
+          ]
+        },
+        {
+          "name": "delegation",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setDefaultWithholdingRate",
+      "discriminator": [
+        228,
+        220,
+        121,
+        51,
+        9,
+        174,
+        20,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "compliance",
+          "signer": true,
+          "relations": [
+            "roles"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "roles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "withholdingRateBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setFeeRecipients",
+      "discriminator": [
+        49,
+        149,
+        195,
+        192,
+        109,
+        40,
+        213,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "property"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "feeRecipient",
+          "type": "pubkey"
+        },
+        {
+          "name": "taxRecipient",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setGovernanceParams",
+      "discriminator": [
+        175,
+        187,
+        3,
+        73,
+        8,
+        251,
+        67,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "property"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "governance",
+          "type": {
+            "defined": {
+              "name": "governanceParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setKycRequired",
+      "discriminator": [
+        119,
+        53,
+        249,
+        92,
+        244,
+        131,
+        242,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "compliance",
+          "signer": true,
+          "relations": [
+            "roles"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "roles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "kycRequired",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "signer": true,
+          "relations": [
+            "roles"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "roles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPlatformFee",
+      "discriminator": [
+        19,
+        70,
+        111,
+        182,
+        156,
+        58,
+        208,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "property"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "platformFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "referralShareBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setRole",
+      "discriminator": [
+        77,
+        78,
+        62,
+        233,
+        192,
+        61,
+        199,
+        190
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "property"
+          ]
+        },
+        {
+          "name": "property"
+        },
+        {
+          "name": "roles",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "role"
+            }
+          }
+        },
+        {
+          "name": "holder",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setWalletCompliance",
+      "discriminator": [
+        12,
+        101,
+        76,
+        192,
+        133,
+        180,
+        16,
+        55
+      ],
+      "accounts": [
+        {
+          "name": "compliance",
+          "signer": true,
+          "relations": [
+            "roles"
+          ]
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property"
+        },
+        {
+          "name": "roles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet"
+        },
+        {
+          "name": "complianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "kycVerified",
+          "type": "bool"
+        },
+        {
+          "name": "frozen",
+          "type": "bool"
+        },
+        {
+          "name": "lockedUntil",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setWithholdingRate",
+      "discriminator": [
+        180,
+        84,
+        156,
+        69,
+        77,
+        24,
+        161,
+        168
+      ],
+      "accounts": [
+        {
+          "name": "compliance",
+          "signer": true,
+          "relations": [
+            "roles"
+          ]
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property"
+        },
+        {
+          "name": "roles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet"
+        },
+        {
+          "name": "complianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "withholdingRateBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "settleAuctionBid",
+      "discriminator": [
+        186,
+        105,
+        86,
+        132,
+        164,
+        123,
+        11,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bidder",
+          "writable": true,
+          "relations": [
+            "auctionBid"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true,
+          "relations": [
+            "auctionBid"
+          ]
+        },
+        {
+          "name": "auctionBid",
+          "writable": true
+        },
+        {
+          "name": "investmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bidder"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "complianceRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "bidder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "propertyMint",
+          "writable": true
+        },
+        {
+          "name": "propertyVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "propertyUsdcAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "bidderPropertyTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "bidder"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "bidderUsdcAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "bidder"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleOrder",
+      "discriminator": [
+        80,
+        74,
+        204,
+        34,
+        12,
+        183,
+        66,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "signer": true
+        },
+        {
+          "name": "owner"
+        },
+        {
+          "name": "property",
+          "relations": [
+            "orderBook"
+          ]
+        },
+        {
+          "name": "propertyMint"
+        },
+        {
+          "name": "orderBook",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  98,
+                  111,
+                  111,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseVault",
+          "writable": true
+        },
+        {
+          "name": "quoteVault",
+          "writable": true
+        },
+        {
+          "name": "ownerInvestmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ownerPropertyTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "ownerUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swap",
+      "discriminator": [
+        248,
+        198,
+        158,
+        145,
+        225,
+        117,
+        135,
+        200
+      ],
+      "accounts": [
+        {
+          "name": "trader",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "property",
+          "relations": [
+            "pool"
+          ]
+        },
+        {
+          "name": "propertyMint"
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseVault",
+          "writable": true
+        },
+        {
+          "name": "quoteVault",
+          "writable": true
+        },
+        {
+          "name": "traderInvestmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "trader"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "traderComplianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "trader"
+              }
+            ]
+          }
+        },
+        {
+          "name": "traderPropertyTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "trader"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "traderUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "side",
+          "type": {
+            "defined": {
+              "name": "orderSide"
+            }
+          }
+        },
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "minAmountOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "transferAdmin",
+      "discriminator": [
+        42,
+        242,
+        66,
+        106,
+        228,
+        10,
+        111,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "property"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferTokens",
+      "discriminator": [
+        54,
+        180,
+        238,
+        175,
+        74,
+        85,
+        126,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "fromTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "toTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "to"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "to"
+        },
+        {
+          "name": "propertyMint",
+          "writable": true
+        },
+        {
+          "name": "property"
+        },
+        {
+          "name": "fromInvestmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "fromComplianceRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "toInvestmentAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "to"
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "toComplianceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "to"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateAdminCouncil",
+      "discriminator": [
+        20,
+        249,
+        166,
+        251,
+        222,
+        241,
+        130,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "council",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "members",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "updateProperty",
+      "discriminator": [
+        232,
+        71,
+        59,
+        188,
+        98,
+        74,
+        94,
+        54
+      ],
+      "accounts": [
+        {
+          "name": "operator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "roles"
+          ]
+        },
+        {
+          "name": "property",
+          "writable": true
+        },
+        {
+          "name": "roles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenSymbol",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "vetoProposal",
+      "discriminator": [
+        177,
+        197,
+        208,
+        96,
+        169,
+        68,
+        23,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "signer": true,
+          "relations": [
+            "roles"
+          ]
+        },
+        {
+          "name": "property",
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "roles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reasonHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "voteOnProposal",
+      "discriminator": [
+        188,
+        239,
+        13,
+        88,
+        119,
+        199,
+        251,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "proposal",
+          "writable": true
+        },
+        {
+          "name": "voteRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal"
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investmentAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              },
+              {
+                "kind": "account",
+                "path": "proposal.property",
+                "account": "Proposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voter",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vote",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "withdrawInvestment",
+      "discriminator": [
+        157,
+        158,
+        101,
+        11,
+        240,
+        193,
+        192,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "investmentAccount"
+          ]
+        },
+        {
+          "name": "investorUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "investmentAccount",
+          "writable": true
+        },
+        {
+          "name": "complianceRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  108,
+                  105,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorPropertyTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "property",
+          "writable": true,
+          "relations": [
+            "investmentAccount"
+          ]
+        },
+        {
+          "name": "propertyMint",
+          "writable": true
+        },
+        {
+          "name": "propertyVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "propertyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "propertyUsdcAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "property"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "adminCouncil",
+      "discriminator": [
+        34,
+        131,
+        90,
+        39,
+        225,
+        74,
+        125,
+        139
+      ]
+    },
+    {
+      "name": "auctionBid",
+      "discriminator": [
+        247,
+        84,
+        228,
+        137,
+        0,
+        60,
+        144,
+        46
+      ]
+    },
+    {
+      "name": "commitment",
+      "discriminator": [
+        61,
+        112,
+        129,
+        128,
+        24,
+        147,
+        77,
+        87
+      ]
+    },
+    {
+      "name": "commitmentRound",
+      "discriminator": [
+        109,
+        138,
+        26,
+        141,
+        116,
+        155,
+        32,
+        151
+      ]
+    },
+    {
+      "name": "complianceRecord",
+      "discriminator": [
+        147,
+        228,
+        164,
+        27,
+        251,
+        44,
+        67,
+        185
+      ]
+    },
+    {
+      "name": "councilAction",
+      "discriminator": [
+        210,
+        145,
+        226,
+        103,
+        135,
+        24,
+        70,
+        123
+      ]
+    },
+    {
+      "name": "delegation",
+      "discriminator": [
+        237,
+        90,
+        140,
+        159,
+        124,
+        255,
+        243,
+        80
+      ]
+    },
+    {
+      "name": "governance",
+      "discriminator": [
+        18,
+        143,
+        88,
+        13,
+        73,
+        217,
+        47,
+        49
+      ]
+    },
+    {
+      "name": "investmentRecord",
+      "discriminator": [
+        221,
+        250,
+        100,
+        99,
+        81,
+        218,
+        9,
+        94
+      ]
+    },
+    {
+      "name": "investor",
+      "discriminator": [
+        174,
+        129,
+        17,
+        83,
+        36,
+        116,
+        26,
+        196
+      ]
+    },
+    {
+      "name": "listing",
+      "discriminator": [
+        218,
+        32,
+        50,
+        73,
+        43,
+        134,
+        26,
+        58
+      ]
+    },
+    {
+      "name": "lpPosition",
+      "discriminator": [
+        105,
+        241,
+        37,
+        200,
+        224,
+        2,
+        252,
+        90
+      ]
+    },
+    {
+      "name": "orderBook",
+      "discriminator": [
+        55,
+        230,
+        125,
+        218,
+        149,
+        39,
+        65,
+        248
+      ]
+    },
+    {
+      "name": "pool",
+      "discriminator": [
+        241,
+        154,
+        109,
+        4,
+        17,
+        177,
+        109,
+        188
+      ]
+    },
+    {
+      "name": "property",
+      "discriminator": [
+        195,
+        247,
+        69,
+        181,
+        195,
+        47,
+        152,
+        19
+      ]
+    },
+    {
+      "name": "proposal",
+      "discriminator": [
+        26,
+        94,
+        189,
+        187,
+        116,
+        136,
+        53,
+        33
+      ]
+    },
+    {
+      "name": "referrerStats",
+      "discriminator": [
+        181,
+        235,
+        242,
+        229,
+        103,
+        242,
+        144,
+        118
+      ]
+    },
+    {
+      "name": "roleAssignment",
+      "discriminator": [
+        205,
+        130,
+        191,
+        231,
+        211,
+        225,
+        155,
+        246
+      ]
+    },
+    {
+      "name": "taxRecord",
+      "discriminator": [
+        4,
+        103,
+        197,
+        222,
+        200,
+        0,
+        5,
+        198
+      ]
+    },
+    {
+      "name": "voteRecord",
+      "discriminator": [
+        112,
+        9,
+        123,
+        165,
+        234,
+        9,
+        157,
+        167
+      ]
+    }
+  ],
+  "events": [
+    {
+      "discriminator": [
+        189,
+        219,
+        127,
+        211,
+        78,
+        230,
+        97,
+        238
+      ],
+      "name": "tradeEvent"
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "unauthorized",
+      "msg": "Unauthorized"
+    },
+    {
+      "code": 6001,
+      "name": "insufficientFunds",
+      "msg": "Insufficient funds"
+    },
+    {
+      "code": 6002,
+      "name": "propertyClosed",
+      "msg": "Property is closed"
+    },
+    {
+      "code": 6003,
+      "name": "propertyNotClosed",
+      "msg": "Property not closed"
+    },
+    {
+      "code": 6004,
+      "name": "tokensRemain",
+      "msg": "Tokens remain"
+    },
+    {
+      "code": 6005,
+      "name": "noTokensOwned",
+      "msg": "No tokens owned"
+    },
+    {
+      "code": 6006,
+      "name": "invalidTotalTokens",
+      "msg": "Invalid total tokens"
+    },
+    {
+      "code": 6007,
+      "name": "invalidTokenPrice",
+      "msg": "Invalid token price"
+    },
+    {
+      "code": 6008,
+      "name": "invalidPropertyName",
+      "msg": "Invalid property name"
+    },
+    {
+      "code": 6009,
+      "name": "invalidTokenSymbol",
+      "msg": "Invalid token symbol"
+    },
+    {
+      "code": 6010,
+      "name": "insufficientAmount",
+      "msg": "Insufficient amount"
+    },
+    {
+      "code": 6011,
+      "name": "notEnoughTokens",
+      "msg": "Not enough tokens"
+    },
+    {
+      "code": 6012,
+      "name": "divisionError",
+      "msg": "Division error"
+    },
     {
-      "name": "voteRecord",
-      "discriminator": [
-        112,
-        9,
-        123,
-        165,
-        234,
-        9,
-        157,
-        167
-      ]
-    }
-  ],
-  "errors": [
+      "code": 6013,
+      "name": "multiplicationError",
+      "msg": "Multiplication error"
+    },
     {
-      "code": 6000,
-      "name": "unauthorized",
-      "msg": "unauthorized"
+      "code": 6014,
+      "name": "overflowError",
+      "msg": "Overflow error"
     },
     {
-      "code": 6001,
-      "name": "insufficientFunds",
-      "msg": "Insufficient funds"
+      "code": 6015,
+      "name": "invalidDividendsClaim",
+      "msg": "Invalid dividends claim"
     },
     {
-      "code": 6002,
-      "name": "propertyClosed",
-      "msg": "Property is closed"
+      "code": 6016,
+      "name": "noDividendsToClaim",
+      "msg": "No dividends to claim"
+    },
+    {
+      "code": 6017,
+      "name": "tokensStillInvested",
+      "msg": "Tokens still invested"
+    },
+    {
+      "code": 6018,
+      "name": "descriptionTooLong",
+      "msg": "Description too long"
+    },
+    {
+      "code": 6019,
+      "name": "proposalAlreadyExecuted",
+      "msg": "Proposal already executed"
+    },
+    {
+      "code": 6020,
+      "name": "alreadyVoted",
+      "msg": "Proposal already voted"
+    },
+    {
+      "code": 6021,
+      "name": "proposalNotApproved",
+      "msg": "Proposal not approved"
+    },
+    {
+      "code": 6022,
+      "name": "invalidProposalType",
+      "msg": "Invalid proposal type"
+    },
+    {
+      "code": 6023,
+      "name": "invalidNewAdmin",
+      "msg": "Invalid new admin"
+    },
+    {
+      "code": 6024,
+      "name": "invalidAdditionalTokens",
+      "msg": "Invalid additional tokens"
+    },
+    {
+      "code": 6025,
+      "name": "invalidProperty",
+      "msg": "Invalid property"
+    },
+    {
+      "code": 6026,
+      "name": "tokensAvailable",
+      "msg": "Tokens available yet"
+    },
+    {
+      "code": 6027,
+      "name": "investorsExist",
+      "msg": "Investors exist"
+    },
+    {
+      "code": 6028,
+      "name": "dividendsExist",
+      "msg": "Dividends exist"
+    },
+    {
+      "code": 6029,
+      "name": "invalidWithholdingRate",
+      "msg": "Invalid withholding rate"
+    },
+    {
+      "code": 6030,
+      "name": "invalidTaxYear",
+      "msg": "Invalid tax year"
+    },
+    {
+      "code": 6031,
+      "name": "invalidTaxVault",
+      "msg": "Invalid tax vault"
+    },
+    {
+      "code": 6032,
+      "name": "quoteExpired",
+      "msg": "Quote expired"
+    },
+    {
+      "code": 6033,
+      "name": "priceAboveLimit",
+      "msg": "Token price above limit"
+    },
+    {
+      "code": 6034,
+      "name": "invalidUsdcAccount",
+      "msg": "Invalid USDC account"
+    },
+    {
+      "code": 6035,
+      "name": "orderBookFull",
+      "msg": "Order book full"
+    },
+    {
+      "code": 6036,
+      "name": "orderNotFound",
+      "msg": "Order not found"
+    },
+    {
+      "code": 6037,
+      "name": "missingMakerAccount",
+      "msg": "Missing maker account"
+    },
+    {
+      "code": 6038,
+      "name": "invalidFee",
+      "msg": "Invalid fee"
+    },
+    {
+      "code": 6039,
+      "name": "slippageExceeded",
+      "msg": "Slippage exceeded"
+    },
+    {
+      "code": 6040,
+      "name": "invalidSaleMode",
+      "msg": "Invalid sale mode"
+    },
+    {
+      "code": 6041,
+      "name": "invalidAuctionParameters",
+      "msg": "Invalid auction parameters"
+    },
+    {
+      "code": 6042,
+      "name": "auctionNotActive",
+      "msg": "Auction not active"
+    },
+    {
+      "code": 6043,
+      "name": "auctionNotEnded",
+      "msg": "Auction not ended"
+    },
+    {
+      "code": 6044,
+      "name": "invalidPriceTranches",
+      "msg": "Invalid price tranches"
+    },
+    {
+      "code": 6045,
+      "name": "invalidCommitmentRound",
+      "msg": "Invalid commitment round"
+    },
+    {
+      "code": 6046,
+      "name": "commitmentRoundNotActive",
+      "msg": "Commitment round not active"
+    },
+    {
+      "code": 6047,
+      "name": "commitmentRoundNotEnded",
+      "msg": "Commitment round not ended"
+    },
+    {
+      "code": 6048,
+      "name": "commitmentRoundNotFinalized",
+      "msg": "Commitment round not finalized"
+    },
+    {
+      "code": 6049,
+      "name": "selfReferral",
+      "msg": "Self-referral is not allowed"
+    },
+    {
+      "code": 6050,
+      "name": "invalidReferrer",
+      "msg": "Invalid referrer"
+    },
+    {
+      "code": 6051,
+      "name": "invalidFeeAccount",
+      "msg": "Invalid fee account"
+    },
+    {
+      "code": 6052,
+      "name": "noVotingPower",
+      "msg": "No voting power"
+    },
+    {
+      "code": 6053,
+      "name": "invalidGovernanceParams",
+      "msg": "Invalid governance parameters"
+    },
+    {
+      "code": 6054,
+      "name": "votingClosed",
+      "msg": "Voting is closed"
+    },
+    {
+      "code": 6055,
+      "name": "votingNotEnded",
+      "msg": "Voting has not ended"
+    },
+    {
+      "code": 6056,
+      "name": "quorumNotReached",
+      "msg": "Quorum not reached"
+    },
+    {
+      "code": 6057,
+      "name": "proposalNotClosable",
+      "msg": "Proposal cannot be closed yet"
+    },
+    {
+      "code": 6058,
+      "name": "proposalNotActive",
+      "msg": "Proposal is not active"
+    },
+    {
+      "code": 6059,
+      "name": "invalidMetadataUri",
+      "msg": "Invalid metadata URI"
+    },
+    {
+      "code": 6060,
+      "name": "investorLimitExceeded",
+      "msg": "Investor token limit exceeded"
+    },
+    {
+      "code": 6061,
+      "name": "propertyLiquidating",
+      "msg": "Property is being liquidated"
+    },
+    {
+      "code": 6062,
+      "name": "insufficientReserve",
+      "msg": "Insufficient reserve funds"
+    },
+    {
+      "code": 6063,
+      "name": "invalidGovernedInstruction",
+      "msg": "Invalid governed instruction"
+    },
+    {
+      "code": 6064,
+      "name": "missingGovernedAccount",
+      "msg": "Missing governed account"
+    },
+    {
+      "code": 6065,
+      "name": "timelockNotElapsed",
+      "msg": "Timelock has not elapsed"
+    },
+    {
+      "code": 6066,
+      "name": "proposalExpired",
+      "msg": "Proposal expired"
+    },
+    {
+      "code": 6067,
+      "name": "invalidDelegate",
+      "msg": "Invalid delegate"
+    },
+    {
+      "code": 6068,
+      "name": "missingDelegationAccount",
+      "msg": "Missing delegation account"
+    },
+    {
+      "code": 6069,
+      "name": "noVoteToChange",
+      "msg": "No vote to change"
+    },
+    {
+      "code": 6070,
+      "name": "invalidRecipient",
+      "msg": "Invalid recipient"
+    },
+    {
+      "code": 6071,
+      "name": "insufficientProposerTokens",
+      "msg": "Proposer does not hold enough tokens"
+    },
+    {
+      "code": 6072,
+      "name": "invalidDepositAccount",
+      "msg": "Invalid deposit account"
+    },
+    {
+      "code": 6073,
+      "name": "invalidCouncil",
+      "msg": "Invalid council members or threshold"
+    },
+    {
+      "code": 6074,
+      "name": "notCouncilMember",
+      "msg": "Signer is not a council member"
+    },
+    {
+      "code": 6075,
+      "name": "alreadyApproved",
+      "msg": "Council member already approved this action"
+    },
+    {
+      "code": 6076,
+      "name": "thresholdNotMet",
+      "msg": "Not enough council approvals"
+    },
+    {
+      "code": 6077,
+      "name": "councilActionExecuted",
+      "msg": "Council action already executed"
+    },
+    {
+      "code": 6078,
+      "name": "invalidRoleHolder",
+      "msg": "Invalid role holder"
+    },
+    {
+      "code": 6079,
+      "name": "walletFrozen",
+      "msg": "Wallet is frozen"
+    },
+    {
+      "code": 6080,
+      "name": "kycRequired",
+      "msg": "Wallet has not passed KYC"
+    },
+    {
+      "code": 6081,
+      "name": "tokensLocked",
+      "msg": "Tokens are still locked up"
+    },
+    {
+      "code": 6082,
+      "name": "propertyPaused",
+      "msg": "Property is paused"
+    },
+    {
+      "code": 6083,
+      "name": "selfCrossingOrder",
+      "msg": "Order would trade against the owner's own resting order"
+    },
+    {
+      "code": 6084,
+      "name": "escrowsOpen",
+      "msg": "Property still holds funds in escrow"
+    },
+    {
+      "code": 6085,
+      "name": "protectedFundsMoved",
+      "msg": "Governed instructions moved funds the property holds for others"
+    },
+    {
+      "code": 6086,
+      "name": "tooManyOpenProposals",
+      "msg": "Too many proposals are open for voting"
+    },
+    {
+      "code": 6087,
+      "name": "orderTooSmall",
+      "msg": "Order is below the minimum order value"
+    }
+  ],
+  "types": [
+    {
+      "name": "adminCouncil",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "members",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "actionCount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionBid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokens",
+            "type": "u64"
+          },
+          {
+            "name": "usdcPaid",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "propertyNotClosed",
-      "msg": "Property not closed"
+      "name": "balanceCheckpoint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "tokensOwned",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "tokensRemain",
-      "msg": "Tokens remain"
+      "name": "commitment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "investor",
+            "type": "pubkey"
+          },
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokens",
+            "type": "u64"
+          },
+          {
+            "name": "usdcCommitted",
+            "type": "u64"
+          },
+          {
+            "name": "isPriority",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "noTokensOwned",
-      "msg": "No tokens owned"
+      "name": "commitmentRound",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenPriceUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "quoteMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokensOffered",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "tokensCommitted",
+            "type": "u64"
+          },
+          {
+            "name": "priorityTokensCommitted",
+            "type": "u64"
+          },
+          {
+            "name": "priorityTokensAllocated",
+            "type": "u64"
+          },
+          {
+            "name": "generalTokensAllocated",
+            "type": "u64"
+          },
+          {
+            "name": "tokensClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "commitmentCount",
+            "type": "u32"
+          },
+          {
+            "name": "finalized",
+            "type": "bool"
+          },
+          {
+            "name": "priorityList",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "invalidTotalTokens",
-      "msg": "Invalid total tokens"
+      "name": "complianceRecord",
+      "docs": [
+        "A wallet's compliance status for one property. Unlike the `Investor`",
+        "position it is never closed, so a rate set by the compliance role stays",
+        "with the wallet through full exits and re-entries."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "withholdingRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "kycVerified",
+            "type": "bool"
+          },
+          {
+            "name": "frozen",
+            "type": "bool"
+          },
+          {
+            "name": "lockedUntil",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "invalidTokenPrice",
-      "msg": "Invalid token price"
+      "name": "councilAction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "council",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "councilActionKind"
+              }
+            }
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "invalidPropertyName",
-      "msg": "Invalid property name"
+      "name": "delegation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "delegator",
+            "type": "pubkey"
+          },
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "invalidTokenSymbol",
-      "msg": "Invalid token symbol"
+      "name": "depositAsset",
+      "docs": [
+        "What a proposer's deposit is paid in."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "usdc"
+          },
+          {
+            "name": "propertyToken"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "insufficientAmount",
-      "msg": "Insufficient amount"
+      "name": "dutchAuction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "floorPrice",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "duration",
+            "type": "i64"
+          },
+          {
+            "name": "clearingPrice",
+            "type": "u64"
+          },
+          {
+            "name": "unsettledBids",
+            "type": "u32"
+          },
+          {
+            "name": "quoteMint",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "notEnoughTokens",
-      "msg": "Not enough tokens"
+      "name": "governance",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposalCount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "divisionError",
-      "msg": "Division error"
+      "name": "governanceParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "votingPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "quorumBps",
+            "type": "u16"
+          },
+          {
+            "name": "approvalThresholdBps",
+            "type": {
+              "array": [
+                "u16",
+                8
+              ]
+            }
+          },
+          {
+            "name": "executionDelay",
+            "type": "i64"
+          },
+          {
+            "name": "gracePeriod",
+            "type": "i64"
+          },
+          {
+            "name": "minProposerTokens",
+            "type": "u64"
+          },
+          {
+            "name": "proposalDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "depositAsset",
+            "type": {
+              "defined": {
+                "name": "depositAsset"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "multiplicationError",
-      "msg": "Multiplication error"
+      "name": "governedAccountMeta",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "pubkey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "overflowError",
-      "msg": "Overflow error"
+      "name": "governedInstruction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "pubkey"
+          },
+          {
+            "name": "accounts",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "governedAccountMeta"
+                }
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "invalidDividendsClaim",
-      "msg": "Invalid dividends claim"
+      "name": "investmentRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "investor",
+            "type": "pubkey"
+          },
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "tokens",
+            "type": "u64"
+          },
+          {
+            "name": "tokenPriceUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "usdcAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "noDividendsToClaim",
-      "msg": "No dividends to claim"
+      "name": "investor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "investor",
+            "type": "pubkey"
+          },
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokensOwned",
+            "type": "u64"
+          },
+          {
+            "name": "dividendsClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "dividendDebt",
+            "type": "u64"
+          },
+          {
+            "name": "dividendsPending",
+            "type": "u64"
+          },
+          {
+            "name": "tokensListed",
+            "type": "u64"
+          },
+          {
+            "name": "orderBaseOwed",
+            "docs": [
+              "Order book fills and released locks waiting for `settle_order`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "orderQuoteOwed",
+            "type": "u64"
+          },
+          {
+            "name": "trancheTokens",
+            "type": {
+              "array": [
+                "u64",
+                4
+              ]
+            }
+          },
+          {
+            "name": "costBasisUsdc",
+            "docs": [
+              "USDC paid for the tokens currently owned."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referrer",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "checkpoints",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "balanceCheckpoint"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "tokensStillInvested",
-      "msg": "Tokens still invested"
+      "name": "listing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "quoteMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "pricePerTokenUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "tokensRemaining",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "descriptionTooLong",
-      "msg": "Description too long"
+      "name": "lpPosition",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "dividendDebt",
+            "type": "u64"
+          },
+          {
+            "name": "dividendsPending",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "proposalAlreadyExecuted",
-      "msg": "Proposal already executed"
+      "name": "order",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "orderId",
+            "type": "u64"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "alreadyVoted",
-      "msg": "Proposal already voted"
+      "name": "orderBook",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "quoteMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "quoteVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "nextOrderId",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "unsettledBase",
+            "docs": [
+              "Fills and released locks credited to investors but not yet settled."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unsettledQuote",
+            "type": "u64"
+          },
+          {
+            "name": "bids",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "order"
+                }
+              }
+            }
+          },
+          {
+            "name": "asks",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "order"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "proposalNotApproved",
-      "msg": "Proposal not approved"
+      "name": "orderSide",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "bid"
+          },
+          {
+            "name": "ask"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "invalidProposalType",
-      "msg": "Invalid proposal type"
+      "name": "pool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "quoteMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "quoteVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseReserve",
+            "type": "u64"
+          },
+          {
+            "name": "quoteReserve",
+            "type": "u64"
+          },
+          {
+            "name": "totalShares",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "dividendDebt",
+            "type": "u64"
+          },
+          {
+            "name": "dividendsPerShare",
+            "type": "u128"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "invalidNewAdmin",
-      "msg": "Invalid new admin"
+      "name": "priceTranche",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokens",
+            "type": "u64"
+          },
+          {
+            "name": "priceUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "tokensSold",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "invalidAdditionalTokens",
-      "msg": "Invalid additional tokens"
+      "name": "property",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "propertyName",
+            "type": "bytes"
+          },
+          {
+            "name": "totalTokens",
+            "type": "u64"
+          },
+          {
+            "name": "availableTokens",
+            "type": "u64"
+          },
+          {
+            "name": "tokenPriceUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSymbol",
+            "type": "bytes"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "dividendsTotal",
+            "type": "u64"
+          },
+          {
+            "name": "isClosed",
+            "type": "bool"
+          },
+          {
+            "name": "dividendsPerToken",
+            "type": "u128"
+          },
+          {
+            "name": "investmentCount",
+            "type": "u64"
+          },
+          {
+            "name": "saleMode",
+            "type": {
+              "defined": {
+                "name": "saleMode"
+              }
+            }
+          },
+          {
+            "name": "dutchAuction",
+            "type": {
+              "defined": {
+                "name": "dutchAuction"
+              }
+            }
+          },
+          {
+            "name": "priceTranches",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "priceTranche"
+                }
+              }
+            }
+          },
+          {
+            "name": "platformFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "referralShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "governance",
+            "type": {
+              "defined": {
+                "name": "governanceParams"
+              }
+            }
+          },
+          {
+            "name": "metadataUri",
+            "type": "string"
+          },
+          {
+            "name": "maxTokensPerInvestor",
+            "type": "u64"
+          },
+          {
+            "name": "isLiquidating",
+            "type": "bool"
+          },
+          {
+            "name": "liquidationPriceUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "proposalDepositsHeld",
+            "type": "u64"
+          },
+          {
+            "name": "defaultWithholdingRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "kycRequired",
+            "type": "bool"
+          },
+          {
+            "name": "isPaused",
+            "type": "bool"
+          },
+          {
+            "name": "openListings",
+            "type": "u32"
+          },
+          {
+            "name": "openProposalDeposits",
+            "type": "u32"
+          },
+          {
+            "name": "saleEscrowHeld",
+            "type": "u64"
+          },
+          {
+            "name": "quoteMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Wallets whose USDC accounts take platform fees and withheld tax. Kept",
+              "apart from the admin and role keys, which may be program accounts."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "taxRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "openSnapshots",
+            "docs": [
+              "Snapshots of proposals that may still be voting, so investor",
+              "checkpoints they read are kept."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "snapshotWindow"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "invalidProperty",
-      "msg": "Invalid property"
+      "name": "proposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "property",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "description",
+            "type": {
+              "array": [
+                "u8",
+                256
+              ]
+            }
+          },
+          {
+            "name": "votesFor",
+            "type": "u64"
+          },
+          {
+            "name": "votesAgainst",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "proposalStatus"
+              }
+            }
+          },
+          {
+            "name": "proposalType",
+            "type": "u8"
+          },
+          {
+            "name": "payload",
+            "type": {
+              "defined": {
+                "name": "proposalPayload"
+              }
+            }
+          },
+          {
+            "name": "votingStart",
+            "type": "i64"
+          },
+          {
+            "name": "snapshotSlot",
+            "type": "u64"
+          },
+          {
+            "name": "votingEnd",
+            "type": "i64"
+          },
+          {
+            "name": "vetoReasonHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "eta",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "depositAmount",
+            "type": "u64"
+          },
+          {
+            "name": "depositAsset",
+            "type": {
+              "defined": {
+                "name": "depositAsset"
+              }
+            }
+          },
+          {
+            "name": "depositAccount",
+            "docs": [
+              "Property-owned account the deposit was paid into, and its mint."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "depositMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "depositCostBasis",
+            "docs": [
+              "Cost basis of a token deposit, handed back with a refund."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "quorumBps",
+            "type": "u16"
+          },
+          {
+            "name": "approvalThresholdBps",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "tokensAvailable",
-      "msg": "Tokens available yet"
+      "name": "proposalPayload",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "mintAdditionalTokens",
+            "fields": [
+              {
+                "name": "additionalTokens",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "changeAdmin",
+            "fields": [
+              {
+                "name": "newAdmin",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "changeTokenPrice",
+            "fields": [
+              {
+                "name": "tokenPriceUsdc",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "approveSale",
+            "fields": [
+              {
+                "name": "liquidationPriceUsdc",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "updateFeeParameters",
+            "fields": [
+              {
+                "name": "platformFeeBps",
+                "type": "u16"
+              },
+              {
+                "name": "referralShareBps",
+                "type": "u16"
+              },
+              {
+                "name": "maxTokensPerInvestor",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "updateMetadataUri",
+            "fields": [
+              {
+                "name": "metadataUri",
+                "type": "string"
+              }
+            ]
+          },
+          {
+            "name": "releaseReserveFunds",
+            "fields": [
+              {
+                "name": "amount",
+                "type": "u64"
+              },
+              {
+                "name": "recipient",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "custom",
+            "fields": [
+              {
+                "name": "instructions",
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "governedInstruction"
+                    }
+                  }
+                }
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6027,
-      "name": "investorsExist",
-      "msg": "Investors exist"
+      "name": "proposalStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
+          },
+          {
+            "name": "cancelled"
+          },
+          {
+            "name": "defeated"
+          },
+          {
+            "name": "succeeded"
+          },
+          {
+            "name": "vetoed"
+          },
+          {
+            "name": "executed"
+          },
+          {
+            "name": "expired"
+          }
+        ]
+      }
     },
     {
-      "code": 6028,
-      "name": "dividendsExist",
-      "msg": "Dividends exist"
-    }
-  ],
-  "types": [
-    {
-      "name": "investor",
+      "name": "referrerStats",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "investor",
+            "name": "referrer",
             "type": "pubkey"
           },
           {
-            "name": "property",
+            "name": "usdcAccount",
             "type": "pubkey"
           },
           {
-            "name": "tokensOwned",
+            "name": "referredInvestors",
             "type": "u64"
           },
           {
-            "name": "dividendsClaimed",
+            "name": "referredVolumeUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "feesEarned",
             "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "property",
+      "name": "role",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "propertyName",
-            "type": "bytes"
+            "name": "operator"
           },
           {
-            "name": "totalTokens",
-            "type": "u64"
+            "name": "treasurer"
           },
           {
-            "name": "availableTokens",
-            "type": "u64"
+            "name": "compliance"
           },
           {
-            "name": "tokenPriceUsdc",
-            "type": "u64"
+            "name": "guardian"
+          }
+        ]
+      }
+    },
+    {
+      "name": "roleAssignment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "property",
+            "type": "pubkey"
           },
           {
-            "name": "tokenSymbol",
-            "type": "bytes"
+            "name": "operator",
+            "type": "pubkey"
           },
           {
-            "name": "admin",
+            "name": "treasurer",
             "type": "pubkey"
           },
           {
-            "name": "mint",
+            "name": "compliance",
+            "type": "pubkey"
+          },
+          {
+            "name": "guardian",
             "type": "pubkey"
           },
           {
             "name": "bump",
             "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "saleMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "fixedPrice"
           },
           {
-            "name": "dividendsTotal",
+            "name": "dutchAuction"
+          },
+          {
+            "name": "commitment"
+          }
+        ]
+      }
+    },
+    {
+      "name": "snapshotWindow",
+      "docs": [
+        "Snapshot slot of a proposal and when its voting ends."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
             "type": "u64"
           },
           {
-            "name": "isClosed",
-            "type": "bool"
+            "name": "votingEnd",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "proposal",
+      "name": "taxRecord",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "proposer",
+            "name": "investor",
             "type": "pubkey"
           },
           {
@@ -1870,55 +10540,86 @@ export type CrowdEstate = {
             "type": "pubkey"
           },
           {
-            "name": "description",
-            "type": {
-              "array": [
-                "u8",
-                256
-              ]
-            }
+            "name": "year",
+            "type": "u16"
           },
           {
-            "name": "votesFor",
+            "name": "withholdingRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "grossDividends",
             "type": "u64"
           },
           {
-            "name": "votesAgainst",
+            "name": "taxWithheld",
             "type": "u64"
           },
           {
-            "name": "isExecuted",
-            "type": "bool"
+            "name": "netDividends",
+            "type": "u64"
           },
           {
-            "name": "proposalType",
-            "type": "u8"
+            "name": "withholdingCount",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tradeEvent",
+      "type": {
+        "fields": [
+          {
+            "name": "property",
+            "type": "pubkey"
           },
           {
-            "name": "newAdmin",
-            "type": {
-              "option": "pubkey"
-            }
+            "name": "makerOrderId",
+            "type": "u64"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "taker",
+            "type": "pubkey"
           },
           {
-            "name": "additionalTokens",
+            "name": "takerSide",
             "type": {
-              "option": "u64"
+              "defined": {
+                "name": "orderSide"
+              }
             }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
-        ]
+        ],
+        "kind": "struct"
       }
     },
     {
-      "name": "proposalType",
+      "name": "voteChoice",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "mintAdditionalTokens"
+            "name": "for"
           },
           {
-            "name": "changeAdmin"
+            "name": "against"
           }
         ]
       }
@@ -1937,8 +10638,22 @@ export type CrowdEstate = {
             "type": "pubkey"
           },
           {
-            "name": "voted",
-            "type": "bool"
+            "name": "choice",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "voteChoice"
+                }
+              }
+            }
+          },
+          {
+            "name": "weight",
+            "type": "u64"
+          },
+          {
+            "name": "castBy",
+            "type": "pubkey"
           }
         ]
       }
@@ -107,6 +107,7 @@ export async function createPropertyTransaction(
 		.accountsPartial({
 			admin: adminPublicKey,
 			propertyMint: propertyMint.publicKey,
+			usdcMint: USDC_MINT,
 			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
 			property: propertyPda,
 			propertyVault: await getAssociatedTokenAddress(
@@ -156,7 +157,7 @@ export async function investInPropertyTransaction(
 	provider: AnchorProvider,
 	program: Program<CrowdEstate>,
 	property: Property,
-	usdcAmount: number,
+	tokens: number,
 	wallet: WalletContextState
 ) {
 	const [investmentPda] = PublicKey.findProgramAddressSync(
@@ -226,30 +227,56 @@ export async function investInPropertyTransaction(
 		true
 	);
 
+	const propertyData = await program.account.property.fetch(
+		new PublicKey(property.publicKey)
+	);
+	const [investmentRecord] = PublicKey.findProgramAddressSync(
+		[
+			Buffer.from("investment_record"),
+			new PublicKey(property.publicKey).toBuffer(),
+			propertyData.investmentCount.toArrayLike(Buffer, "le", 8),
+		],
+		program.programId
+	);
+
 	const accounts = {
 		property: new PublicKey(property.publicKey),
 		propertyMint: new PublicKey(property.mint),
+		usdcMint: USDC_MINT,
 		investor: wallet.publicKey,
 		investmentAccount: investmentPda,
+		investmentRecord,
 		propertyUsdcAccount: propertyUsdcAta,
 		investorUsdcAccount: investorUsdcAta,
 		investorPropertyTokenAccount: investorPropertyAta,
 		propertyVault: propertyVaultAta,
+		platformFeeAccount:
+			propertyData.platformFeeBps > 0
+				? await getAssociatedTokenAddress(
+						USDC_MINT,
+						propertyData.feeRecipient,
+						true
+					)
+				: null,
+		referrerStats: null,
+		referrerUsdcAccount: null,
 		tokenProgram: TOKEN_PROGRAM_ID,
 		systemProgram: SystemProgram.programId,
 	};
 
-	const usdcAmountBN = new anchor.BN(
-		(BigInt(usdcAmount) * BigInt(1e6)).toString()
+	// Accept up to the listed price plus the platform fee, quoted for the
+	// next ~150 slots.
+	const maxTokenPriceUsdc = propertyData.tokenPriceUsdc
+		.muln(10_000 + propertyData.platformFeeBps)
+		.addn(9_999)
+		.divn(10_000);
+	const expirySlot = new anchor.BN(
+		(await provider.connection.getSlot()) + 150
 	);
 
-	// console.log("Invest in property", {
-	// 	usdcAmount,
-	// 	usdcAmountBN,
-	// });
 	const investIx = await program.methods
-		.investInProperty(usdcAmountBN)
-		.accountsStrict(accounts)
+		.investInProperty(new anchor.BN(tokens), maxTokenPriceUsdc, expirySlot)
+		.accountsPartial(accounts)
 		.instruction();
 	tx.add(investIx);
 
@@ -282,6 +309,7 @@ export async function withdrawInvestment(
 	program: Program<CrowdEstate>,
 	investment: Investment,
 	propertyData: Property,
+	tokens: number,
 	wallet: WalletContextState
 ): Promise<{ txSignature: string; investmentPda: string }> {
 	const investmentPda = investment.publicKey;
@@ -316,17 +344,27 @@ export async function withdrawInvestment(
 	const accounts = {
 		property: new PublicKey(investment.property),
 		propertyMint: new PublicKey(propertyData.mint),
+		usdcMint: USDC_MINT,
 		investor: wallet.publicKey,
 		investmentAccount: investmentPda,
 		propertyUsdcAccount: propertyUsdcAta,
 		investorUsdcAccount: investorUsdcAta,
+		investorPropertyTokenAccount: await getAssociatedTokenAddress(
+			new PublicKey(propertyData.mint),
+			wallet.publicKey
+		),
+		propertyVault: await getAssociatedTokenAddress(
+			new PublicKey(propertyData.mint),
+			new PublicKey(investment.property),
+			true
+		),
 		adminUsdcAccount: adminUsdcAddress,
 		tokenProgram: TOKEN_PROGRAM_ID,
 		systemProgram: SystemProgram.programId,
 	};
 	const withdrawIx = await program.methods
-		.withdrawInvestment()
-		.accounts(accounts)
+		.withdrawInvestment(new anchor.BN(tokens))
+		.accountsPartial(accounts)
 		.instruction();
 	tx.add(withdrawIx);
 	tx.recentBlockhash = (
@@ -356,6 +394,72 @@ export async function withdrawInvestment(
 	return { txSignature, investmentPda: investmentPda.toString() };
 }
 
+export async function redeemDividendsTransaction(
+	provider: AnchorProvider,
+	program: Program<CrowdEstate>,
+	investment: Investment,
+	wallet: WalletContextState
+): Promise<string> {
+	const property = new PublicKey(investment.property);
+	const tx = new Transaction();
+	const investorUsdcAta = await ensureAssociatedTokenAccount(
+		provider.connection,
+		tx,
+		USDC_MINT,
+		wallet.publicKey,
+		wallet.publicKey
+	);
+
+	// Withheld tax is paid to the property's tax recipient's USDC account.
+	const { taxRecipient } = await program.account.property.fetch(property);
+	const taxVault = await ensureAssociatedTokenAccount(
+		provider.connection,
+		tx,
+		USDC_MINT,
+		taxRecipient,
+		wallet.publicKey,
+		true
+	);
+
+	// Dividends are booked against the tax year they are redeemed in.
+	const year = new Date().getUTCFullYear();
+	const redeemIx = await program.methods
+		.redeemDividends(year)
+		.accountsPartial({
+			investor: wallet.publicKey,
+			property,
+			investmentAccount: new PublicKey(investment.publicKey),
+			propertyUsdcAccount: await getAssociatedTokenAddress(
+				USDC_MINT,
+				property,
+				true
+			),
+			usdcMint: USDC_MINT,
+			investorUsdcAccount: investorUsdcAta,
+			taxVault,
+			tokenProgram: TOKEN_PROGRAM_ID,
+			systemProgram: SystemProgram.programId,
+		})
+		.instruction();
+	tx.add(redeemIx);
+	tx.recentBlockhash = (
+		await provider.connection.getLatestBlockhash()
+	).blockhash;
+	tx.feePayer = wallet.publicKey;
+
+	const signedTx = await wallet.signTransaction(tx);
+	const txSignature = await provider.connection.sendRawTransaction(
+		signedTx.serialize(),
+		{
+			skipPreflight: false,
+			preflightCommitment: "confirmed",
+		}
+	);
+	await provider.connection.confirmTransaction(txSignature, "confirmed");
+
+	return txSignature;
+}
+
 export async function distributeDividendsTransaction(
 	provider: AnchorProvider,
 	program: Program<CrowdEstate>,
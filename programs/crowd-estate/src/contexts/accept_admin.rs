@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{Property, RoleAssignment};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        mut,
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    /// Completes an admin handover; the new admin also takes over every role
+    /// the old admin held.
+    pub fn accept_admin(&mut self) -> Result<()> {
+        let property = &mut self.property;
+
+        require!(
+            property.pending_admin == Some(self.new_admin.key()),
+            crate::errors::Errors::InvalidNewAdmin
+        );
+
+        self.roles.reassign(property.admin, self.new_admin.key());
+        property.admin = self.new_admin.key();
+        property.pending_admin = None;
+
+        Ok(())
+    }
+}
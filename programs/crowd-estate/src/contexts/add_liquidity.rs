@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{integer_sqrt, mul_div, ComplianceRecord, Investor, LpPosition, Pool, Property};
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty)]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = property,
+        seeds = [b"pool", property.key().as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, address = pool.base_vault)]
+    pub base_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, address = pool.quote_vault)]
+    pub quote_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"investment", provider.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub provider_investment_account: Account<'info, Investor>,
+
+    #[account(
+        seeds = [b"compliance", property.key().as_ref(), provider.key().as_ref()],
+        bump,
+    )]
+    pub provider_compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = LpPosition::INIT_SPACE,
+        seeds = [b"lp_position", pool.key().as_ref(), provider.key().as_ref()],
+        bump,
+    )]
+    pub lp_position: Account<'info, LpPosition>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_property_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = provider_usdc_account.mint == pool.quote_mint @ crate::errors::Errors::InvalidUsdcAccount,
+    )]
+    pub provider_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddLiquidity<'info> {
+    pub fn add_liquidity(
+        &mut self,
+        base_amount: u64,
+        max_quote_amount: u64,
+        min_shares: u64,
+    ) -> Result<()> {
+        require!(
+            !self.property.is_closed,
+            crate::errors::Errors::PropertyClosed
+        );
+        require!(
+            !self.property.is_paused,
+            crate::errors::Errors::PropertyPaused
+        );
+        require!(
+            !self.property.is_liquidating,
+            crate::errors::Errors::PropertyLiquidating
+        );
+        require!(base_amount > 0, crate::errors::Errors::InsufficientAmount);
+        self.provider_compliance_record
+            .check_can_send(Clock::get()?.unix_timestamp)?;
+
+        let dividends_per_token = self.property.dividends_per_token;
+        let pool = &mut self.pool;
+        let lp_position = &mut self.lp_position;
+
+        pool.sync_dividends(dividends_per_token)?;
+        if lp_position.owner == Pubkey::default() {
+            lp_position.owner = self.provider.key();
+            lp_position.pool = pool.key();
+        }
+        lp_position.settle_dividends(pool.dividends_per_share)?;
+
+        // The first deposit sets the price; later ones must match the pool ratio.
+        let (quote_amount, shares) = if pool.total_shares == 0 {
+            let shares = integer_sqrt(
+                (base_amount as u128)
+                    .checked_mul(max_quote_amount as u128)
+                    .ok_or(crate::errors::Errors::MultiplicationError)?,
+            ) as u64;
+            (max_quote_amount, shares)
+        } else {
+            let quote_amount = mul_div(
+                base_amount as u128,
+                pool.quote_reserve as u128,
+                pool.base_reserve as u128,
+            )?
+            .checked_add(1)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+            let shares = mul_div(
+                base_amount as u128,
+                pool.total_shares as u128,
+                pool.base_reserve as u128,
+            )?;
+            (quote_amount, shares)
+        };
+
+        require!(
+            quote_amount <= max_quote_amount,
+            crate::errors::Errors::SlippageExceeded
+        );
+        require!(
+            shares > 0 && shares >= min_shares,
+            crate::errors::Errors::SlippageExceeded
+        );
+
+        let provider_investment_account = &mut self.provider_investment_account;
+        require!(
+            provider_investment_account.unlisted_tokens() >= base_amount,
+            crate::errors::Errors::NotEnoughTokens
+        );
+        provider_investment_account.remove_tokens(base_amount, &self.property)?;
+
+        let cpi_accounts = Transfer {
+            from: self.provider_property_token_account.to_account_info(),
+            to: self.base_vault.to_account_info(),
+            authority: self.provider.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, base_amount)?;
+
+        let cpi_accounts = Transfer {
+            from: self.provider_usdc_account.to_account_info(),
+            to: self.quote_vault.to_account_info(),
+            authority: self.provider.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, quote_amount)?;
+
+        pool.base_reserve = pool
+            .base_reserve
+            .checked_add(base_amount)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        pool.quote_reserve = pool
+            .quote_reserve
+            .checked_add(quote_amount)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        pool.total_shares = pool
+            .total_shares
+            .checked_add(shares)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        pool.reset_dividend_debt(dividends_per_token)?;
+
+        lp_position.shares = lp_position
+            .shares
+            .checked_add(shares)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        lp_position.reset_dividend_debt(pool.dividends_per_share)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{AdminCouncil, CouncilAction};
+
+#[derive(Accounts)]
+pub struct ApproveCouncilAction<'info> {
+    pub member: Signer<'info>,
+
+    pub council: Account<'info, AdminCouncil>,
+
+    #[account(mut, has_one = council)]
+    pub council_action: Account<'info, CouncilAction>,
+}
+
+impl<'info> ApproveCouncilAction<'info> {
+    pub fn approve_council_action(&mut self) -> Result<()> {
+        let member = self.member.key();
+        let council_action = &mut self.council_action;
+
+        require!(
+            self.council.is_member(&member),
+            crate::errors::Errors::NotCouncilMember
+        );
+        require!(
+            !council_action.executed,
+            crate::errors::Errors::CouncilActionExecuted
+        );
+        require!(
+            !council_action.approvals.contains(&member),
+            crate::errors::Errors::AlreadyApproved
+        );
+
+        // Drop approvals from wallets that have since left the council.
+        let council = &self.council;
+        council_action
+            .approvals
+            .retain(|approval| council.is_member(approval));
+        council_action.approvals.push(member);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::{Investor, Listing, Property};
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    pub payer: Signer<'info>,
+
+    /// CHECK: receives the listing's rent; pinned by `has_one = seller`.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty,
+    )]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = seller,
+        has_one = seller,
+        has_one = property,
+        seeds = [b"listing", property.key().as_ref(), seller.key().as_ref()],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = listing,
+    )]
+    pub listing_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"investment", seller.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub seller_investment_account: Account<'info, Investor>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = seller,
+    )]
+    pub seller_property_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelListing<'info> {
+    /// Anyone may cancel once the property is liquidating, so a leftover
+    /// listing cannot keep the property from closing.
+    pub fn cancel_listing(&mut self) -> Result<()> {
+        require!(
+            self.payer.key() == self.seller.key() || self.property.is_liquidating,
+            crate::errors::Errors::Unauthorized
+        );
+
+        let listing = &self.listing;
+
+        let property_key = self.property.key();
+        let seller_key = self.seller.key();
+        let seeds = &[
+            b"listing",
+            property_key.as_ref(),
+            seller_key.as_ref(),
+            &[listing.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: self.listing_vault.to_account_info(),
+            to: self.seller_property_token_account.to_account_info(),
+            authority: listing.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, listing.tokens_remaining)?;
+
+        let cpi_accounts = CloseAccount {
+            account: self.listing_vault.to_account_info(),
+            destination: self.seller.to_account_info(),
+            authority: listing.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::close_account(cpi_ctx)?;
+
+        self.seller_investment_account.tokens_listed = self
+            .seller_investment_account
+            .tokens_listed
+            .checked_sub(listing.tokens_remaining)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        self.property.open_listings -= 1;
+
+        Ok(())
+    }
+}
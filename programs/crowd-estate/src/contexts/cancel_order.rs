@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{Investor, OrderBook, OrderSide, Property};
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    pub payer: Signer<'info>,
+
+    /// CHECK: only identifies the order's owner, checked against the order.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty)]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = property,
+        seeds = [b"order_book", property.key().as_ref()],
+        bump = order_book.bump,
+    )]
+    pub order_book: Account<'info, OrderBook>,
+
+    #[account(mut, address = order_book.base_vault)]
+    pub base_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, address = order_book.quote_vault)]
+    pub quote_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"investment", owner.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub owner_investment_account: Option<Account<'info, Investor>>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_property_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = owner_usdc_account.owner == owner.key() @ crate::errors::Errors::InvalidUsdcAccount,
+        constraint = owner_usdc_account.mint == order_book.quote_mint @ crate::errors::Errors::InvalidUsdcAccount,
+    )]
+    pub owner_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CancelOrder<'info> {
+    /// Removes the order, returning its unfilled lock. Fills are settled
+    /// separately through `settle_order`. Only asks need the owner's position,
+    /// so a bid can be cancelled after the position was closed. Anyone may
+    /// cancel once the property is liquidating.
+    pub fn cancel_order(&mut self, order_id: u64) -> Result<()> {
+        require!(
+            self.payer.key() == self.owner.key() || self.property.is_liquidating,
+            crate::errors::Errors::Unauthorized
+        );
+
+        let (side, index) = self
+            .order_book
+            .find_order(order_id)
+            .ok_or(crate::errors::Errors::OrderNotFound)?;
+        let order = self.order_book.orders_mut(side).remove(index);
+
+        require!(
+            order.owner == self.owner.key(),
+            crate::errors::Errors::Unauthorized
+        );
+
+        let (base_amount, quote_amount) = match side {
+            OrderSide::Ask => {
+                let owner_investment_account = self
+                    .owner_investment_account
+                    .as_mut()
+                    .ok_or(crate::errors::Errors::MissingMakerAccount)?;
+                owner_investment_account.tokens_listed = owner_investment_account
+                    .tokens_listed
+                    .checked_sub(order.quantity)
+                    .ok_or(crate::errors::Errors::OverflowError)?;
+
+                (order.quantity, 0)
+            }
+            OrderSide::Bid => (
+                0,
+                order
+                    .quantity
+                    .checked_mul(order.price)
+                    .ok_or(crate::errors::Errors::MultiplicationError)?,
+            ),
+        };
+        let property_key = self.property.key();
+        let seeds = &[
+            b"order_book",
+            property_key.as_ref(),
+            &[self.order_book.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        if base_amount > 0 {
+            let cpi_accounts = Transfer {
+                from: self.base_vault.to_account_info(),
+                to: self.owner_property_token_account.to_account_info(),
+                authority: self.order_book.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, base_amount)?;
+        }
+
+        if quote_amount > 0 {
+            let cpi_accounts = Transfer {
+                from: self.quote_vault.to_account_info(),
+                to: self.owner_usdc_account.to_account_info(),
+                authority: self.order_book.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, quote_amount)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{Proposal, ProposalStatus};
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    pub proposer: Signer<'info>,
+
+    #[account(mut, has_one = proposer)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> CancelProposal<'info> {
+    pub fn cancel_proposal(&mut self) -> Result<()> {
+        let proposal = &mut self.proposal;
+
+        require!(
+            proposal.status == ProposalStatus::Active,
+            crate::errors::Errors::ProposalNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp < proposal.voting_end,
+            crate::errors::Errors::VotingClosed
+        );
+
+        proposal.status = ProposalStatus::Cancelled;
+
+        Ok(())
+    }
+}
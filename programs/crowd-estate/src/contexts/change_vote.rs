@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{Proposal, VoteChoice, VoteRecord};
+
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        has_one = proposal,
+        constraint = authority.key() == vote_record.voter
+            || authority.key() == vote_record.cast_by @ crate::errors::Errors::Unauthorized,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+impl<'info> ChangeVote<'info> {
+    /// Moves the record's weight to the other side. Either the holder or the
+    /// delegate who cast the vote may switch it; the signer becomes `cast_by`.
+    pub fn change_vote(&mut self, vote: bool) -> Result<()> {
+        let proposal = &mut self.proposal;
+        let vote_record = &mut self.vote_record;
+
+        require!(
+            proposal.is_voting_open(Clock::get()?.unix_timestamp),
+            crate::errors::Errors::VotingClosed
+        );
+
+        let choice = VoteChoice::from(vote);
+        let previous = vote_record
+            .choice
+            .ok_or(crate::errors::Errors::NoVoteToChange)?;
+        require!(previous != choice, crate::errors::Errors::NoVoteToChange);
+
+        proposal.remove_votes(previous, vote_record.weight)?;
+        proposal.add_votes(choice, vote_record.weight)?;
+
+        vote_record.choice = Some(choice);
+        vote_record.cast_by = self.authority.key();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{Commitment, CommitmentRound, ComplianceRecord, Investor, Property};
+
+#[derive(Accounts)]
+pub struct ClaimCommitment<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The committing wallet; pinned by `has_one` on the commitment.
+    #[account(mut)]
+    pub investor: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        mut,
+        seeds = [b"commitment_round", property.key().as_ref()],
+        bump = commitment_round.bump,
+    )]
+    pub commitment_round: Account<'info, CommitmentRound>,
+
+    #[account(
+        mut,
+        close = investor,
+        has_one = investor,
+        has_one = property,
+    )]
+    pub commitment: Account<'info, Commitment>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Investor::INIT_SPACE,
+        seeds = [b"investment", investor.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub investment_account: Account<'info, Investor>,
+
+    #[account(
+        seeds = [b"compliance", property.key().as_ref(), investor.key().as_ref()],
+        bump,
+    )]
+    pub compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(mut, constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty)]
+    pub property_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = property,
+    )]
+    pub property_vault: Account<'info, TokenAccount>,
+
+    #[account(address = commitment_round.quote_mint @ crate::errors::Errors::InvalidUsdcAccount)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = property,
+    )]
+    pub property_usdc_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = property_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_property_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimCommitment<'info> {
+    /// Delivers the tokens allocated to the commitment and refunds the USDC
+    /// that was not allocated, both to the investor's associated token
+    /// accounts. Anyone may crank a claim. A wallet that can no longer hold
+    /// the tokens gets all of its USDC back instead. The last claim returns
+    /// rounding leftovers and refused allocations to the fixed-price sale.
+    pub fn claim_commitment(&mut self) -> Result<()> {
+        let property = &mut self.property;
+        let round = &mut self.commitment_round;
+        let commitment = &self.commitment;
+
+        require!(
+            round.finalized,
+            crate::errors::Errors::CommitmentRoundNotFinalized
+        );
+
+        let tokens = if self.compliance_record.check_can_receive(property).is_ok() {
+            round.allocation(commitment.tokens, commitment.is_priority)?
+        } else {
+            0
+        };
+        let refund = commitment
+            .usdc_committed
+            .checked_sub(
+                tokens
+                    .checked_mul(round.token_price_usdc)
+                    .ok_or(crate::errors::Errors::MultiplicationError)?,
+            )
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        let seeds = &[
+            b"property",
+            property.creator.as_ref(),
+            &property.property_name,
+            &[property.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        if tokens > 0 {
+            let cpi_accounts = Transfer {
+                from: self.property_vault.to_account_info(),
+                to: self.investor_property_token_account.to_account_info(),
+                authority: property.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, tokens)?;
+        }
+
+        if refund > 0 {
+            let cpi_accounts = Transfer {
+                from: self.property_usdc_account.to_account_info(),
+                to: self.investor_usdc_account.to_account_info(),
+                authority: property.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, refund)?;
+        }
+
+        let investment_account = &mut self.investment_account;
+        if investment_account.investor == Pubkey::default() {
+            investment_account.investor = self.investor.key();
+            investment_account.property = property.key();
+        }
+        investment_account.add_tokens(tokens, commitment.usdc_committed - refund, property)?;
+
+        // What was not refunded is now sale proceeds.
+        property.sale_escrow_held -= commitment.usdc_committed;
+        round.tokens_claimed += tokens;
+        round.commitment_count -= 1;
+        if round.commitment_count == 0 {
+            property.available_tokens = property
+                .available_tokens
+                .checked_add(round.tokens_allocated() - round.tokens_claimed)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{ComplianceRecord, Investor, LpPosition, Pool, Property};
+
+#[derive(Accounts)]
+pub struct ClaimPoolDividends<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub property: Account<'info, Property>,
+
+    #[account(
+        mut,
+        has_one = property,
+        seeds = [b"pool", property.key().as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = owner, has_one = pool)]
+    pub lp_position: Account<'info, LpPosition>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = Investor::INIT_SPACE,
+        seeds = [b"investment", owner.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub owner_investment_account: Account<'info, Investor>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub owner_compliance_record: Account<'info, ComplianceRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimPoolDividends<'info> {
+    /// Moves the LP's share of dividends earned by pooled tokens onto its
+    /// `Investor` position, to be paid out (and withheld) by `redeem_dividends`.
+    pub fn claim_pool_dividends(&mut self) -> Result<()> {
+        let pool = &mut self.pool;
+        let lp_position = &mut self.lp_position;
+
+        pool.sync_dividends(self.property.dividends_per_token)?;
+        lp_position.settle_dividends(pool.dividends_per_share)?;
+
+        let dividends = lp_position.dividends_pending;
+        require!(dividends > 0, crate::errors::Errors::NoDividendsToClaim);
+        lp_position.dividends_pending = 0;
+
+        let owner_investment_account = &mut self.owner_investment_account;
+        if owner_investment_account.investor == Pubkey::default() {
+            owner_investment_account.investor = self.owner.key();
+            owner_investment_account.property = self.property.key();
+            owner_investment_account.reset_dividend_debt(self.property.dividends_per_token)?;
+        }
+        self.owner_compliance_record.register(
+            self.owner.key(),
+            self.property.key(),
+            self.property.default_withholding_rate_bps,
+        );
+        owner_investment_account.dividends_pending = owner_investment_account
+            .dividends_pending
+            .checked_add(dividends)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::Property;
+
+#[derive(Accounts)]
+pub struct CloseAuction<'info> {
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+}
+
+impl<'info> CloseAuction<'info> {
+    /// Lets anyone end a Dutch auction once it is over, so an auction nobody
+    /// bid in does not keep the property out of fixed-price sales.
+    pub fn close_auction(&mut self) -> Result<()> {
+        self.property
+            .end_dutch_auction(Clock::get()?.unix_timestamp)
+    }
+}
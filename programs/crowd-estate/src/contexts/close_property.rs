@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount, self, Burn};
 
-use crate::Property;
+use crate::{CommitmentRound, OrderBook, Pool, Property, RoleAssignment};
 
 #[derive(Accounts)]
 pub struct CloseProperty<'info> {
@@ -13,8 +13,30 @@ pub struct CloseProperty<'info> {
     )]
     pub property: Account<'info, Property>,
 
+    pub operator: Signer<'info>,
+
+    /// CHECK: only receives the property's rent; pinned by `has_one = admin`.
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+        has_one = operator,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+
+    /// CHECK: may not exist yet; read by `load_if_created`.
+    #[account(seeds = [b"order_book", property.key().as_ref()], bump)]
+    pub order_book: UncheckedAccount<'info>,
+
+    /// CHECK: may not exist yet; read by `load_if_created`.
+    #[account(seeds = [b"pool", property.key().as_ref()], bump)]
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: may not exist yet; read by `load_if_created`.
+    #[account(seeds = [b"commitment_round", property.key().as_ref()], bump)]
+    pub commitment_round: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub admin_usdc_account: Account<'info, TokenAccount>,
@@ -38,10 +60,33 @@ impl<'info> CloseProperty<'info> {
         let property = &mut self.property;
 
         require!(!property.is_closed, crate::errors::Errors::PropertyClosed);
+
+        // Escrows are released through instructions that load the property,
+        // so closing it first would strand whatever they hold.
         require!(
-            property.admin == self.admin.key(),
-            crate::errors::Errors::Unauthorized
+            property.open_listings == 0
+                && property.open_proposal_deposits == 0
+                && property.dutch_auction.unsettled_bids == 0,
+            crate::errors::Errors::EscrowsOpen
         );
+        if let Some(order_book) = load_if_created::<OrderBook>(&self.order_book)? {
+            require!(
+                order_book.bids.is_empty()
+                    && order_book.asks.is_empty()
+                    && order_book.unsettled_base == 0
+                    && order_book.unsettled_quote == 0,
+                crate::errors::Errors::EscrowsOpen
+            );
+        }
+        if let Some(pool) = load_if_created::<Pool>(&self.pool)? {
+            require!(pool.total_shares == 0, crate::errors::Errors::EscrowsOpen);
+        }
+        if let Some(round) = load_if_created::<CommitmentRound>(&self.commitment_round)? {
+            require!(
+                round.commitment_count == 0,
+                crate::errors::Errors::EscrowsOpen
+            );
+        }
 
         let cpi_accounts = Burn {
             mint: self.property_mint.to_account_info(),
@@ -51,7 +96,7 @@ impl<'info> CloseProperty<'info> {
 
         let seeds = &[
             b"property",
-            property.admin.as_ref(),
+            property.creator.as_ref(),
             &property.property_name,
             &[property.bump],
         ];
@@ -68,4 +113,14 @@ impl<'info> CloseProperty<'info> {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Deserializes a program account at a fixed address, or `None` if it has not
+/// been created.
+fn load_if_created<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+
+    T::try_deserialize(&mut &info.data.borrow()[..]).map(Some)
+}
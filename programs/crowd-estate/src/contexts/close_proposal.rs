@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{DepositAsset, Investor, Property, Proposal, ProposalStatus};
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    /// CHECK: Receives the proposal's rent; pinned by `has_one` on the proposal.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer,
+        has_one = property,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [b"investment", proposer.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub investment_account: Option<Account<'info, Investor>>,
+
+    #[account(mut)]
+    pub proposer_deposit_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub property_deposit_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseProposal<'info> {
+    /// Returns the proposal's rent to the proposer once it can no longer be
+    /// executed: it was executed, cancelled, vetoed, defeated or has expired.
+    /// The deposit is refunded if the proposal reached quorum and forfeited
+    /// to the property otherwise. Anyone may close, so a proposer cannot
+    /// hold off a forfeit by leaving the proposal open.
+    pub fn close_proposal(&mut self) -> Result<()> {
+        let proposal = &mut self.proposal;
+
+        if proposal.status == ProposalStatus::Active {
+            require!(
+                Clock::get()?.unix_timestamp >= proposal.voting_end,
+                crate::errors::Errors::ProposalNotClosable
+            );
+            proposal.finalize(&self.property)?;
+        }
+        if proposal.is_expired(Clock::get()?.unix_timestamp) {
+            proposal.status = ProposalStatus::Expired;
+        }
+        require!(
+            proposal.status != ProposalStatus::Succeeded,
+            crate::errors::Errors::ProposalNotClosable
+        );
+
+        if proposal.deposit_amount > 0 {
+            self.settle_deposit()?;
+        }
+
+        Ok(())
+    }
+
+    fn settle_deposit(&mut self) -> Result<()> {
+        let proposal = &self.proposal;
+        let property = &mut self.property;
+        let amount = proposal.deposit_amount;
+        let refund = proposal.reached_quorum(property);
+        property.open_proposal_deposits -= 1;
+
+        match proposal.deposit_asset {
+            DepositAsset::Usdc => {
+                property.proposal_deposits_held = property
+                    .proposal_deposits_held
+                    .checked_sub(amount)
+                    .ok_or(crate::errors::Errors::OverflowError)?;
+            }
+            DepositAsset::PropertyToken if refund => {
+                let investment_account = self
+                    .investment_account
+                    .as_mut()
+                    .ok_or(crate::errors::Errors::InvalidDepositAccount)?;
+                investment_account.add_tokens(amount, proposal.deposit_cost_basis, property)?;
+            }
+            // Forfeited tokens go back on sale with the rest of the vault.
+            DepositAsset::PropertyToken => {
+                property.available_tokens = property
+                    .available_tokens
+                    .checked_add(amount)
+                    .ok_or(crate::errors::Errors::OverflowError)?;
+            }
+        }
+
+        if !refund {
+            return Ok(());
+        }
+
+        let proposer_deposit_account = self
+            .proposer_deposit_account
+            .as_ref()
+            .ok_or(crate::errors::Errors::InvalidDepositAccount)?;
+        let property_deposit_account = self
+            .property_deposit_account
+            .as_ref()
+            .ok_or(crate::errors::Errors::InvalidDepositAccount)?;
+        require!(
+            property_deposit_account.key() == proposal.deposit_account
+                && proposer_deposit_account.owner == proposal.proposer
+                && proposer_deposit_account.mint == proposal.deposit_mint,
+            crate::errors::Errors::InvalidDepositAccount
+        );
+
+        let seeds = &[
+            b"property",
+            property.creator.as_ref(),
+            &property.property_name,
+            &[property.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: property_deposit_account.to_account_info(),
+            to: proposer_deposit_account.to_account_info(),
+            authority: property.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, amount)
+    }
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{Commitment, CommitmentRound, ComplianceRecord, Property, SaleMode};
+
+#[derive(Accounts)]
+pub struct CommitToRound<'info> {
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        mut,
+        seeds = [b"commitment_round", property.key().as_ref()],
+        bump = commitment_round.bump,
+    )]
+    pub commitment_round: Account<'info, CommitmentRound>,
+
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = Commitment::INIT_SPACE,
+        seeds = [b"commitment", property.key().as_ref(), investor.key().as_ref()],
+        bump,
+    )]
+    pub commitment: Account<'info, Commitment>,
+
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), investor.key().as_ref()],
+        bump,
+    )]
+    pub compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(mut)]
+    pub investor_usdc_account: Account<'info, TokenAccount>,
+
+    #[account(address = commitment_round.quote_mint @ crate::errors::Errors::InvalidUsdcAccount)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = property,
+    )]
+    pub property_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CommitToRound<'info> {
+    /// Escrows USDC for `tokens` at the round price. Commitments may exceed
+    /// the offering; the excess is refunded when the investor claims.
+    pub fn commit_to_round(&mut self, tokens: u64) -> Result<()> {
+        let round = &mut self.commitment_round;
+
+        require!(
+            !self.property.is_paused,
+            crate::errors::Errors::PropertyPaused
+        );
+        require!(
+            self.property.sale_mode == SaleMode::Commitment
+                && !round.finalized
+                && Clock::get()?.unix_timestamp < round.end_time,
+            crate::errors::Errors::CommitmentRoundNotActive
+        );
+        require!(tokens > 0, crate::errors::Errors::InsufficientAmount);
+        self.compliance_record.register(
+            self.investor.key(),
+            self.property.key(),
+            self.property.default_withholding_rate_bps,
+        );
+        self.compliance_record.check_can_receive(&self.property)?;
+
+        let usdc_amount = tokens
+            .checked_mul(round.token_price_usdc)
+            .ok_or(crate::errors::Errors::MultiplicationError)?;
+
+        let cpi_accounts = Transfer {
+            from: self.investor_usdc_account.to_account_info(),
+            to: self.property_usdc_account.to_account_info(),
+            authority: self.investor.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, usdc_amount)?;
+
+        self.property.sale_escrow_held = self
+            .property
+            .sale_escrow_held
+            .checked_add(usdc_amount)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        let commitment = &mut self.commitment;
+        if commitment.investor == Pubkey::default() {
+            commitment.investor = self.investor.key();
+            commitment.property = self.property.key();
+            commitment.is_priority = round.priority_list.contains(&commitment.investor);
+            round.commitment_count = round
+                .commitment_count
+                .checked_add(1)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+        }
+        commitment.tokens = commitment
+            .tokens
+            .checked_add(tokens)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        commitment.usdc_committed = commitment
+            .usdc_committed
+            .checked_add(usdc_amount)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        round.tokens_committed = round
+            .tokens_committed
+            .checked_add(tokens)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        if commitment.is_priority {
+            round.priority_tokens_committed += tokens;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{DutchAuction, Property, SaleMode};
+
+#[derive(Accounts)]
+pub struct ConfigureDutchAuction<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut, has_one = admin)]
+    pub property: Account<'info, Property>,
+
+    pub usdc_mint: Account<'info, Mint>,
+}
+
+impl<'info> ConfigureDutchAuction<'info> {
+    pub fn configure_dutch_auction(
+        &mut self,
+        start_price: u64,
+        floor_price: u64,
+        start_time: i64,
+        duration: i64,
+    ) -> Result<()> {
+        let property = &mut self.property;
+
+        require!(!property.is_closed, crate::errors::Errors::PropertyClosed);
+        require!(
+            property.sale_mode == SaleMode::FixedPrice
+                && property.dutch_auction.unsettled_bids == 0,
+            crate::errors::Errors::InvalidSaleMode
+        );
+        require!(
+            floor_price > 0 && start_price >= floor_price && duration > 0,
+            crate::errors::Errors::InvalidAuctionParameters
+        );
+        require!(
+            start_time >= Clock::get()?.unix_timestamp,
+            crate::errors::Errors::InvalidAuctionParameters
+        );
+
+        property.sale_mode = SaleMode::DutchAuction;
+        property.dutch_auction = DutchAuction {
+            start_price,
+            floor_price,
+            start_time,
+            duration,
+            clearing_price: 0,
+            unsettled_bids: 0,
+            quote_mint: self.usdc_mint.key(),
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{AdminCouncil, Property, RoleAssignment};
+
+#[derive(Accounts)]
+pub struct CreateAdminCouncil<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, has_one = admin)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminCouncil::INIT_SPACE,
+        seeds = [b"admin_council", property.key().as_ref()],
+        bump,
+    )]
+    pub council: Account<'info, AdminCouncil>,
+
+    #[account(
+        mut,
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateAdminCouncil<'info> {
+    /// Hands the property's admin role, and any other role the admin holds,
+    /// to a council of `members`, any `threshold` of whom can run admin
+    /// actions from then on.
+    pub fn create_admin_council(
+        &mut self,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        bump: u8,
+    ) -> Result<()> {
+        AdminCouncil::validate(&members, threshold)?;
+
+        self.council.set_inner(AdminCouncil {
+            property: self.property.key(),
+            members,
+            threshold,
+            action_count: 0,
+            bump,
+        });
+
+        let council = self.council.key();
+        self.roles.reassign(self.admin.key(), council);
+
+        let property = &mut self.property;
+        property.admin = council;
+        property.pending_admin = None;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{ComplianceRecord, Investor, Listing, Property};
+
+#[derive(Accounts)]
+pub struct CreateListing<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty,
+    )]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"investment", seller.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub seller_investment_account: Account<'info, Investor>,
+
+    #[account(
+        seeds = [b"compliance", property.key().as_ref(), seller.key().as_ref()],
+        bump,
+    )]
+    pub seller_compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = seller,
+    )]
+    pub seller_property_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = Listing::INIT_SPACE,
+        seeds = [b"listing", property.key().as_ref(), seller.key().as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        init,
+        payer = seller,
+        associated_token::mint = property_mint,
+        associated_token::authority = listing,
+    )]
+    pub listing_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateListing<'info> {
+    pub fn create_listing(
+        &mut self,
+        tokens: u64,
+        price_per_token_usdc: u64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(!self.property.is_closed, crate::errors::Errors::PropertyClosed);
+        require!(!self.property.is_paused, crate::errors::Errors::PropertyPaused);
+        require!(
+            !self.property.is_liquidating,
+            crate::errors::Errors::PropertyLiquidating
+        );
+        require!(tokens > 0, crate::errors::Errors::InsufficientAmount);
+        require!(
+            price_per_token_usdc > 0,
+            crate::errors::Errors::InvalidTokenPrice
+        );
+        self.seller_compliance_record
+            .check_can_send(Clock::get()?.unix_timestamp)?;
+
+        let seller_investment_account = &mut self.seller_investment_account;
+        require!(
+            seller_investment_account.unlisted_tokens() >= tokens,
+            crate::errors::Errors::NotEnoughTokens
+        );
+
+        let cpi_accounts = Transfer {
+            from: self.seller_property_token_account.to_account_info(),
+            to: self.listing_vault.to_account_info(),
+            authority: self.seller.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, tokens)?;
+
+        // Listed tokens keep earning dividends for the seller until they are bought.
+        seller_investment_account.tokens_listed = seller_investment_account
+            .tokens_listed
+            .checked_add(tokens)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        let property = &mut self.property;
+        property.open_listings = property
+            .open_listings
+            .checked_add(1)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        self.listing.set_inner(Listing {
+            seller: self.seller.key(),
+            property: self.property.key(),
+            quote_mint: self.usdc_mint.key(),
+            price_per_token_usdc,
+            tokens_remaining: tokens,
+            bump,
+        });
+
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use anchor_spl::{
     token::{self, Mint, MintTo, Token, TokenAccount},
 };
 
-use crate::Property;
+use crate::{DutchAuction, GovernanceParams, Property, RoleAssignment, SaleMode};
 
 #[derive(Accounts)]
 #[instruction(property_name: String, total_tokens: u64, token_price_usdc: u64, token_symbol: String, bump: u8)]
@@ -21,6 +21,15 @@ pub struct CreateProperty<'info> {
     )]
     pub property: Account<'info, Property>,
 
+    #[account(
+        init,
+        payer = admin,
+        space = RoleAssignment::INIT_SPACE,
+        seeds = [b"roles", property.key().as_ref()],
+        bump
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+
     #[account(
         mut,
         mint::decimals = 0,
@@ -37,6 +46,9 @@ pub struct CreateProperty<'info> {
     )]
     pub property_vault: Account<'info, TokenAccount>,
 
+    /// The stablecoin the property is bought, redeemed and paid out in.
+    pub usdc_mint: Account<'info, Mint>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -50,6 +62,7 @@ impl<'info> CreateProperty<'info> {
         token_price_usdc: u64,
         token_symbol: String,
         bump: u8,
+        roles_bump: u8,
     ) -> Result<()> {
         require!(total_tokens > 0, crate::errors::Errors::InvalidTotalTokens);
         require!(
@@ -69,6 +82,8 @@ impl<'info> CreateProperty<'info> {
 
         self.property.set_inner(Property {
             admin,
+            pending_admin: None,
+            creator: admin,
             property_name: property_name.as_bytes().to_vec(),
             total_tokens,
             available_tokens: total_tokens,
@@ -78,6 +93,39 @@ impl<'info> CreateProperty<'info> {
             bump,
             dividends_total: 0,
             is_closed: false,
+            dividends_per_token: 0,
+            investment_count: 0,
+            sale_mode: SaleMode::FixedPrice,
+            dutch_auction: DutchAuction::default(),
+            price_tranches: Vec::new(),
+            platform_fee_bps: 0,
+            referral_share_bps: 0,
+            governance: GovernanceParams::default(),
+            metadata_uri: String::new(),
+            max_tokens_per_investor: 0,
+            is_liquidating: false,
+            liquidation_price_usdc: 0,
+            proposal_deposits_held: 0,
+            default_withholding_rate_bps: 0,
+            kyc_required: false,
+            is_paused: false,
+            open_listings: 0,
+            open_proposal_deposits: 0,
+            sale_escrow_held: 0,
+            quote_mint: self.usdc_mint.key(),
+            fee_recipient: admin,
+            tax_recipient: admin,
+            open_snapshots: Vec::new(),
+        });
+
+        // Every role starts with the admin until it is handed out.
+        self.roles.set_inner(RoleAssignment {
+            property: self.property.key(),
+            operator: admin,
+            treasurer: admin,
+            compliance: admin,
+            guardian: admin,
+            bump: roles_bump,
         });
 
         msg!("Creating property vault for property: {}", property_name);
@@ -1,16 +1,34 @@
-use std::str::FromStr;
-
 use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{self, Token, TokenAccount, Transfer},
+};
 
-use crate::{Property, Proposal, ProposalType};
+use crate::{
+    DepositAsset, Governance, Investor, Property, Proposal, ProposalPayload, ProposalStatus,
+    SnapshotWindow,
+};
 
 #[derive(Accounts)]
 pub struct CreateProposal<'info> {
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = Governance::INIT_SPACE,
+        seeds = [b"governance", property.key().as_ref()],
+        bump
+    )]
+    pub governance: Account<'info, Governance>,
+
     #[account(
         init,
         payer = proposer,
         space = Proposal::INIT_SPACE,
-        seeds = [b"proposal", proposer.key().as_ref(), property.key().as_ref()],
+        seeds = [
+            b"proposal",
+            property.key().as_ref(),
+            &governance.proposal_count.to_le_bytes(),
+        ],
         bump
     )]
     pub proposal: Account<'info, Proposal>,
@@ -21,6 +39,24 @@ pub struct CreateProposal<'info> {
     #[account(mut)]
     pub property: Account<'info, Property>,
 
+    #[account(
+        mut,
+        seeds = [b"investment", proposer.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub investment_account: Option<Account<'info, Investor>>,
+
+    /// The proposer's USDC or property token account, matching the
+    /// property's deposit asset.
+    #[account(mut)]
+    pub proposer_deposit_account: Option<Account<'info, TokenAccount>>,
+
+    /// The property's USDC associated token account or its vault, whichever
+    /// matches the deposit asset.
+    #[account(mut)]
+    pub property_deposit_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
@@ -28,9 +64,8 @@ impl<'info> CreateProposal<'info> {
     pub fn create_proposal(
         &mut self,
         description: String,
-        proposal_type: ProposalType,
-        new_admin: String,
-        additional_tokens: u64,
+        payload: ProposalPayload,
+        governance_bump: u8,
     ) -> Result<()> {
         require!(
             description.len() <= 256,
@@ -40,42 +75,146 @@ impl<'info> CreateProposal<'info> {
         let mut description_bytes = [0u8; 256];
         description_bytes[..description.len()].copy_from_slice(description.as_bytes());
 
-        let proposal_new_admin: Option<Pubkey>;
-        let proposal_additional_tokens: Option<u64>;
-
-        match proposal_type {
-            ProposalType::ChangeAdmin => {
-                require!(
-                    !new_admin.is_empty(),
-                    crate::errors::Errors::InvalidNewAdmin
-                );
-                let new_admin_key = Pubkey::from_str(&new_admin)
-                    .map_err(|_| crate::errors::Errors::InvalidNewAdmin)?;
-                proposal_new_admin = Some(new_admin_key);
-                proposal_additional_tokens = None;
-            }
-            ProposalType::MintAdditionalTokens => {
-                require!(
-                    additional_tokens > 0,
-                    crate::errors::Errors::InvalidAdditionalTokens
-                );
-                proposal_new_admin = None;
-                proposal_additional_tokens = Some(additional_tokens);
-            }
+        payload.validate()?;
+
+        let governance_params = self.property.governance;
+        let approval_threshold_bps =
+            governance_params.approval_threshold(payload.proposal_type() as u8)?;
+        if governance_params.min_proposer_tokens > 0 {
+            let tokens_owned = self
+                .investment_account
+                .as_ref()
+                .map_or(0, |investment_account| investment_account.tokens_owned);
+            require!(
+                tokens_owned >= governance_params.min_proposer_tokens,
+                crate::errors::Errors::InsufficientProposerTokens
+            );
         }
+        let (deposit_account, deposit_mint, deposit_cost_basis) =
+            if governance_params.proposal_deposit > 0 {
+                self.take_deposit(
+                    governance_params.proposal_deposit,
+                    governance_params.deposit_asset,
+                )?
+            } else {
+                (Pubkey::default(), Pubkey::default(), 0)
+            };
+
+        let voting_start = Clock::get()?.unix_timestamp;
+        let voting_end = voting_start
+            .checked_add(governance_params.voting_period)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        let eta = voting_end
+            .checked_add(governance_params.execution_delay)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        let expires_at = eta
+            .checked_add(governance_params.grace_period)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        let snapshot_slot = Clock::get()?.slot;
+        self.property.open_snapshot(
+            SnapshotWindow {
+                slot: snapshot_slot,
+                voting_end,
+            },
+            voting_start,
+        )?;
+
+        let governance = &mut self.governance;
+        governance.property = self.property.key();
+        governance.bump = governance_bump;
+        let index = governance.proposal_count;
+        governance.proposal_count = index
+            .checked_add(1)
+            .ok_or(crate::errors::Errors::OverflowError)?;
 
         self.proposal.set_inner(Proposal {
             proposer: self.proposer.key(),
             property: self.property.key(),
+            index,
             description: description_bytes,
             votes_for: 0,
             votes_against: 0,
-            is_executed: false,
-            proposal_type: proposal_type.clone() as u8,
-            new_admin: proposal_new_admin,
-            additional_tokens: proposal_additional_tokens,
+            status: ProposalStatus::Active,
+            proposal_type: payload.proposal_type() as u8,
+            payload,
+            voting_start,
+            snapshot_slot,
+            voting_end,
+            veto_reason_hash: [0u8; 32],
+            eta,
+            expires_at,
+            deposit_amount: governance_params.proposal_deposit,
+            deposit_asset: governance_params.deposit_asset,
+            deposit_account,
+            deposit_mint,
+            deposit_cost_basis,
+            quorum_bps: governance_params.quorum_bps,
+            approval_threshold_bps,
         });
 
         Ok(())
     }
+
+    /// Moves the deposit from the proposer into the property's custody until
+    /// the proposal is closed. USDC deposits are tracked so they are never
+    /// spent as reserve; token deposits leave the proposer's position.
+    /// Returns the account the deposit was paid into, its mint and the cost
+    /// basis of a token deposit.
+    fn take_deposit(&mut self, amount: u64, asset: DepositAsset) -> Result<(Pubkey, Pubkey, u64)> {
+        let proposer_deposit_account = self
+            .proposer_deposit_account
+            .as_ref()
+            .ok_or(crate::errors::Errors::InvalidDepositAccount)?;
+        let property_deposit_account = self
+            .property_deposit_account
+            .as_ref()
+            .ok_or(crate::errors::Errors::InvalidDepositAccount)?;
+
+        let property = &mut self.property;
+        property.open_proposal_deposits = property
+            .open_proposal_deposits
+            .checked_add(1)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        let is_property_token = asset == DepositAsset::PropertyToken;
+        let deposit_mint = if is_property_token {
+            property.mint
+        } else {
+            property.quote_mint
+        };
+        require!(
+            property_deposit_account.key()
+                == get_associated_token_address(&property.key(), &deposit_mint)
+                && proposer_deposit_account.owner == self.proposer.key()
+                && proposer_deposit_account.mint == deposit_mint,
+            crate::errors::Errors::InvalidDepositAccount
+        );
+
+        let mut cost_basis = 0;
+        if is_property_token {
+            let investment_account = self
+                .investment_account
+                .as_mut()
+                .ok_or(crate::errors::Errors::NotEnoughTokens)?;
+            require!(
+                investment_account.unlisted_tokens() >= amount,
+                crate::errors::Errors::NotEnoughTokens
+            );
+            cost_basis = investment_account.remove_tokens(amount, property)?;
+        } else {
+            property.proposal_deposits_held = property
+                .proposal_deposits_held
+                .checked_add(amount)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+        }
+
+        let cpi_accounts = Transfer {
+            from: proposer_deposit_account.to_account_info(),
+            to: property_deposit_account.to_account_info(),
+            authority: self.proposer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+
+        Ok((property_deposit_account.key(), deposit_mint, cost_basis))
+    }
 }
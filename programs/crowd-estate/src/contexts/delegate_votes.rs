@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{Delegation, Property};
+
+#[derive(Accounts)]
+pub struct DelegateVotes<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    pub property: Account<'info, Property>,
+
+    #[account(
+        init_if_needed,
+        payer = delegator,
+        space = Delegation::INIT_SPACE,
+        seeds = [b"delegation", property.key().as_ref(), delegator.key().as_ref()],
+        bump,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DelegateVotes<'info> {
+    /// Points the delegator's voting power for the property at `delegate`,
+    /// replacing any earlier delegation.
+    pub fn delegate_votes(&mut self, delegate: Pubkey, bump: u8) -> Result<()> {
+        require!(
+            delegate != self.delegator.key() && delegate != Pubkey::default(),
+            crate::errors::Errors::InvalidDelegate
+        );
+
+        self.delegation.set_inner(Delegation {
+            delegator: self.delegator.key(),
+            property: self.property.key(),
+            delegate,
+            bump,
+        });
+
+        Ok(())
+    }
+}
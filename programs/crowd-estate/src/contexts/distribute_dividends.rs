@@ -1,20 +1,27 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
 
-use crate::Property;
+use crate::{Property, RoleAssignment};
 
 #[derive(Accounts)]
 #[instruction(total_dividends: u64)]
 pub struct DistributeDividends<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub treasurer: Signer<'info>,
 
     #[account(mut)]
-    pub admin_usdc_account: Account<'info, TokenAccount>,
+    pub treasurer_usdc_account: Account<'info, TokenAccount>,
 
     #[account(mut)]
     pub property: Account<'info, Property>,
 
+    #[account(
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+        has_one = treasurer,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+
     // #[account(mut)]
     // pub property_usdc_account: Account<'info, TokenAccount>,
 
@@ -26,15 +33,10 @@ impl<'info> DistributeDividends<'info> {
     pub fn distribute_dividends(&mut self, total_dividends: u64) -> Result<()> {
         let property = &mut self.property;
 
-        require!(
-            property.admin == self.admin.key(),
-            crate::errors::Errors::Unauthorized
-        );
-
         // let cpi_accounts = Transfer {
-        //     from: self.admin_usdc_account.to_account_info(),
+        //     from: self.treasurer_usdc_account.to_account_info(),
         //     to: self.property_usdc_account.to_account_info(),
-        //     authority: self.admin_usdc_account.to_account_info(),
+        //     authority: self.treasurer_usdc_account.to_account_info(),
         // };
         // let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
         // token::transfer(cpi_ctx, total_dividends)?;
@@ -44,6 +46,16 @@ impl<'info> DistributeDividends<'info> {
             .checked_add(total_dividends)
             .ok_or(crate::errors::Errors::OverflowError)?;
 
+        let dividends_per_token = (total_dividends as u128)
+            .checked_mul(Property::DIVIDEND_PRECISION)
+            .ok_or(crate::errors::Errors::MultiplicationError)?
+            .checked_div(property.total_tokens as u128)
+            .ok_or(crate::errors::Errors::DivisionError)?;
+        property.dividends_per_token = property
+            .dividends_per_token
+            .checked_add(dividends_per_token)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
         Ok(())
     }
 }
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+
+use crate::{program::CrowdEstate, AdminCouncil, CouncilAction};
+
+#[derive(Accounts)]
+pub struct ExecuteCouncilAction<'info> {
+    pub member: Signer<'info>,
+
+    pub council: Account<'info, AdminCouncil>,
+
+    #[account(mut, has_one = council)]
+    pub council_action: Account<'info, CouncilAction>,
+
+    pub crowd_estate_program: Program<'info, CrowdEstate>,
+}
+
+impl<'info> ExecuteCouncilAction<'info> {
+    /// Runs the approved action by invoking the matching admin instruction
+    /// with the council signing as admin. `remaining_accounts` are that
+    /// instruction's accounts, in order, with the council in the admin slot.
+    pub fn execute_council_action(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let council = &self.council;
+        let council_action = &mut self.council_action;
+
+        require!(
+            council.is_member(&self.member.key()),
+            crate::errors::Errors::NotCouncilMember
+        );
+        require!(
+            !council_action.executed,
+            crate::errors::Errors::CouncilActionExecuted
+        );
+        require!(
+            council.approvals_from_members(&council_action.approvals) >= council.threshold as usize,
+            crate::errors::Errors::ThresholdNotMet
+        );
+
+        let council_key = council.key();
+        let instruction = Instruction {
+            program_id: crate::ID,
+            accounts: remaining_accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer || account.key() == council_key,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: council_action.action.instruction_data(),
+        };
+
+        let mut account_infos = remaining_accounts.to_vec();
+        account_infos.push(self.crowd_estate_program.to_account_info());
+
+        let seeds = &[b"admin_council", council.property.as_ref(), &[council.bump]];
+        let signer_seeds = &[&seeds[..]];
+        invoke_signed(&instruction, &account_infos, signer_seeds)?;
+
+        council_action.executed = true;
+
+        Ok(())
+    }
+}
@@ -1,17 +1,23 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{self, Mint, MintTo, Token, TokenAccount, Transfer},
+};
 
-use crate::{Property, Proposal};
+use crate::{Property, Proposal, ProposalPayload, ProposalStatus};
 
 #[derive(Accounts)]
 pub struct ExecuteProposal<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"proposal", property.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump
+    )]
     pub proposal: Account<'info, Proposal>,
 
     #[account(mut)]
     pub admin: Signer<'info>,
-    #[account(mut)]
-    pub new_admin: Option<Signer<'info>>,
 
     #[account(mut)]
     pub property: Account<'info, Property>,
@@ -29,69 +35,225 @@ pub struct ExecuteProposal<'info> {
     #[account(mut)]
     pub destination_token_account: Account<'info, TokenAccount>,
 
+    #[account(mut)]
+    pub property_usdc_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub recipient_usdc_account: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ExecuteProposal<'info> {
-    pub fn execute_proposal(&mut self) -> Result<()> {
+    /// `remaining_accounts` must hold every account and program referenced by
+    /// a `Custom` proposal's instructions, other than the property itself. A
+    /// `Custom` proposal also needs the property's USDC account, so its
+    /// balance can be checked once the instructions have run.
+    pub fn execute_proposal(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
         let proposal = &mut self.proposal;
 
         require!(
-            !proposal.is_executed,
+            proposal.status != ProposalStatus::Executed,
             crate::errors::Errors::ProposalAlreadyExecuted
         );
+        if proposal.status == ProposalStatus::Active {
+            proposal.finalize(&self.property)?;
+        }
         require!(
-            proposal.votes_for > proposal.votes_against,
+            proposal.status == ProposalStatus::Succeeded,
             crate::errors::Errors::ProposalNotApproved
         );
 
-        match proposal.proposal_type {
-            0 => {
-                let admin = self.admin.key();
+        // The timelock gives dissenting holders time to exit before the change lands.
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= proposal.eta, crate::errors::Errors::TimelockNotElapsed);
+        require!(
+            !proposal.is_expired(now),
+            crate::errors::Errors::ProposalExpired
+        );
 
-                self.property.available_tokens = self
-                    .property
-                    .available_tokens
-                    .checked_add(proposal.additional_tokens.unwrap())
-                    .ok_or(crate::errors::Errors::OverflowError)?;
-                self.property.total_tokens = self
-                    .property
-                    .total_tokens
-                    .checked_add(proposal.additional_tokens.unwrap())
-                    .ok_or(crate::errors::Errors::OverflowError)?;
+        let property = &mut self.property;
+        let seeds = &[
+            b"property",
+            property.creator.as_ref(),
+            &property.property_name,
+            &[property.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
 
+        match proposal.payload.clone() {
+            ProposalPayload::MintAdditionalTokens { additional_tokens } => {
                 let cpi_accounts = MintTo {
                     mint: self.property_mint.to_account_info(),
                     to: self.property_vault.to_account_info(),
-                    authority: self.property.to_account_info(),
+                    authority: property.to_account_info(),
                 };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds,
+                );
+                token::mint_to(cpi_ctx, additional_tokens)?;
+
+                property.available_tokens = property
+                    .available_tokens
+                    .checked_add(additional_tokens)
+                    .ok_or(crate::errors::Errors::OverflowError)?;
+                property.total_tokens = property
+                    .total_tokens
+                    .checked_add(additional_tokens)
+                    .ok_or(crate::errors::Errors::OverflowError)?;
+            }
+            // The new admin takes over once they accept with `accept_admin`.
+            ProposalPayload::ChangeAdmin { new_admin } => {
+                property.pending_admin = Some(new_admin);
+            }
+            ProposalPayload::ChangeTokenPrice { token_price_usdc } => {
+                property.token_price_usdc = token_price_usdc;
+            }
+            ProposalPayload::ApproveSale {
+                liquidation_price_usdc,
+            } => {
+                property.is_liquidating = true;
+                property.liquidation_price_usdc = liquidation_price_usdc;
+            }
+            ProposalPayload::UpdateFeeParameters {
+                platform_fee_bps,
+                referral_share_bps,
+                max_tokens_per_investor,
+            } => {
+                property.platform_fee_bps = platform_fee_bps;
+                property.referral_share_bps = referral_share_bps;
+                property.max_tokens_per_investor = max_tokens_per_investor;
+            }
+            ProposalPayload::UpdateMetadataUri { metadata_uri } => {
+                property.metadata_uri = metadata_uri;
+            }
+            ProposalPayload::ReleaseReserveFunds { amount, recipient } => {
+                let property_usdc_account = self
+                    .property_usdc_account
+                    .as_ref()
+                    .ok_or(crate::errors::Errors::InvalidUsdcAccount)?;
+                let recipient_usdc_account = self
+                    .recipient_usdc_account
+                    .as_ref()
+                    .ok_or(crate::errors::Errors::InvalidUsdcAccount)?;
+                require!(
+                    property_usdc_account.owner == property.key()
+                        && recipient_usdc_account.key() == recipient,
+                    crate::errors::Errors::InvalidUsdcAccount
+                );
 
-                let seeds = &[
-                    b"property",
-                    admin.as_ref(),
-                    &self.property.property_name,
-                    &[self.property.bump],
-                ];
-                let signer_seeds = &[&seeds[..]];
+                // Dividends already distributed belong to holders, proposal
+                // deposits to their proposers and unsettled commitments and
+                // auction bids to their investors, not the reserve.
+                let reserve = property_usdc_account
+                    .amount
+                    .saturating_sub(property.dividends_total)
+                    .saturating_sub(property.proposal_deposits_held)
+                    .saturating_sub(property.sale_escrow_held);
+                require!(
+                    amount <= reserve,
+                    crate::errors::Errors::InsufficientReserve
+                );
 
+                let cpi_accounts = Transfer {
+                    from: property_usdc_account.to_account_info(),
+                    to: recipient_usdc_account.to_account_info(),
+                    authority: property.to_account_info(),
+                };
                 let cpi_ctx = CpiContext::new_with_signer(
                     self.token_program.to_account_info(),
                     cpi_accounts,
                     signer_seeds,
                 );
-                token::mint_to(cpi_ctx, proposal.additional_tokens.unwrap())?;
+                token::transfer(cpi_ctx, amount)?;
             }
-            1 => {
-                self.property.admin = self.new_admin.as_ref().unwrap().key();
-            }
-            _ => {
-                return Err(crate::errors::Errors::InvalidProposalType.into());
+            ProposalPayload::Custom { instructions } => {
+                let property_usdc_account = self
+                    .property_usdc_account
+                    .as_mut()
+                    .ok_or(crate::errors::Errors::InvalidUsdcAccount)?;
+                require!(
+                    property_usdc_account.key()
+                        == get_associated_token_address(&property.key(), &property.quote_mint),
+                    crate::errors::Errors::InvalidUsdcAccount
+                );
+                let vault_tokens = self.property_vault.amount;
+
+                let find_account = |key: &Pubkey| -> Result<AccountInfo<'info>> {
+                    remaining_accounts
+                        .iter()
+                        .find(|account| account.key == key)
+                        .cloned()
+                        .ok_or(crate::errors::Errors::MissingGovernedAccount.into())
+                };
+
+                for governed in instructions.iter() {
+                    let instruction = Instruction::from(governed);
+                    check_governed_instruction(&instruction, &property.key())?;
+
+                    let mut account_infos = Vec::with_capacity(instruction.accounts.len() + 1);
+                    for meta in instruction.accounts.iter() {
+                        if meta.pubkey == property.key() {
+                            account_infos.push(property.to_account_info());
+                        } else {
+                            account_infos.push(find_account(&meta.pubkey)?);
+                        }
+                    }
+                    account_infos.push(find_account(&instruction.program_id)?);
+
+                    invoke_signed(&instruction, &account_infos, signer_seeds)?;
+                }
+
+                // The instructions ran with the property's signature, so make
+                // sure they left what is owed to holders, proposers and
+                // investors where it was.
+                property_usdc_account.reload()?;
+                self.property_vault.reload()?;
+                let protected_usdc = property
+                    .dividends_total
+                    .checked_add(property.proposal_deposits_held)
+                    .and_then(|held| held.checked_add(property.sale_escrow_held))
+                    .ok_or(crate::errors::Errors::OverflowError)?;
+                require!(
+                    property_usdc_account.amount >= protected_usdc
+                        && self.property_vault.amount >= vault_tokens,
+                    crate::errors::Errors::ProtectedFundsMoved
+                );
             }
         }
 
-        proposal.is_executed = true;
+        proposal.status = ProposalStatus::Executed;
 
         Ok(())
     }
 }
+
+/// SPL Token `SetAuthority` and `CloseAccount` only need the property's
+/// signature to act on its mint or the accounts it owns, and either would take
+/// them out of the program's hands, so governed instructions may not use them.
+fn check_governed_instruction(instruction: &Instruction, property: &Pubkey) -> Result<()> {
+    const SET_AUTHORITY: u8 = 6;
+    const CLOSE_ACCOUNT: u8 = 9;
+
+    let changes_authority = instruction.program_id == token::ID
+        && matches!(
+            instruction.data.first(),
+            Some(&SET_AUTHORITY) | Some(&CLOSE_ACCOUNT)
+        );
+    require!(
+        !changes_authority
+            || instruction
+                .accounts
+                .iter()
+                .all(|meta| meta.pubkey != *property),
+        crate::errors::Errors::InvalidGovernedInstruction
+    );
+
+    Ok(())
+}
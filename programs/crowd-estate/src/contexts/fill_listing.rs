@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::{ComplianceRecord, Investor, Listing, Property};
+
+#[derive(Accounts)]
+pub struct FillListing<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(mut)]
+    pub seller: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty,
+    )]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = seller,
+        has_one = property,
+        seeds = [b"listing", property.key().as_ref(), seller.key().as_ref()],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = listing,
+    )]
+    pub listing_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"investment", seller.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub seller_investment_account: Account<'info, Investor>,
+
+    #[account(
+        seeds = [b"compliance", property.key().as_ref(), seller.key().as_ref()],
+        bump,
+    )]
+    pub seller_compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = Investor::INIT_SPACE,
+        seeds = [b"investment", buyer.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub buyer_investment_account: Account<'info, Investor>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub buyer_compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(
+        mut,
+        constraint = seller_usdc_account.owner == seller.key() @ crate::errors::Errors::InvalidUsdcAccount,
+        constraint = seller_usdc_account.mint == listing.quote_mint @ crate::errors::Errors::InvalidUsdcAccount,
+    )]
+    pub seller_usdc_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = buyer_usdc_account.mint == listing.quote_mint @ crate::errors::Errors::InvalidUsdcAccount,
+    )]
+    pub buyer_usdc_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_property_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FillListing<'info> {
+    pub fn fill_listing(&mut self, tokens: u64, max_price_per_token_usdc: u64) -> Result<()> {
+        let listing = &mut self.listing;
+        let property = &self.property;
+
+        require!(!property.is_closed, crate::errors::Errors::PropertyClosed);
+        require!(!property.is_paused, crate::errors::Errors::PropertyPaused);
+        require!(tokens > 0, crate::errors::Errors::InsufficientAmount);
+        require!(
+            listing.tokens_remaining >= tokens,
+            crate::errors::Errors::NotEnoughTokens
+        );
+        require!(
+            listing.price_per_token_usdc <= max_price_per_token_usdc,
+            crate::errors::Errors::PriceAboveLimit
+        );
+        require!(
+            self.buyer.key() != listing.seller,
+            crate::errors::Errors::Unauthorized
+        );
+        // A frozen or locked-up seller cannot sell into a listing made earlier.
+        self.seller_compliance_record
+            .check_can_send(Clock::get()?.unix_timestamp)?;
+
+        let usdc_amount = tokens
+            .checked_mul(listing.price_per_token_usdc)
+            .ok_or(crate::errors::Errors::MultiplicationError)?;
+
+        let cpi_accounts = Transfer {
+            from: self.buyer_usdc_account.to_account_info(),
+            to: self.seller_usdc_account.to_account_info(),
+            authority: self.buyer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, usdc_amount)?;
+
+        let property_key = property.key();
+        let seller_key = listing.seller;
+        let seeds = &[
+            b"listing",
+            property_key.as_ref(),
+            seller_key.as_ref(),
+            &[listing.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: self.listing_vault.to_account_info(),
+            to: self.buyer_property_token_account.to_account_info(),
+            authority: listing.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, tokens)?;
+
+        // The seller keeps what its position earned up to now; the buyer only
+        // earns dividends distributed after the fill.
+        let seller_investment_account = &mut self.seller_investment_account;
+        seller_investment_account.remove_tokens(tokens, property)?;
+        seller_investment_account.tokens_listed -= tokens;
+
+        let buyer_investment_account = &mut self.buyer_investment_account;
+        if buyer_investment_account.investor == Pubkey::default() {
+            buyer_investment_account.investor = self.buyer.key();
+            buyer_investment_account.property = property_key;
+        }
+        self.buyer_compliance_record.register(
+            self.buyer.key(),
+            property_key,
+            self.property.default_withholding_rate_bps,
+        );
+        self.buyer_compliance_record
+            .check_can_receive(&self.property)?;
+        buyer_investment_account.add_tokens(tokens, usdc_amount, property)?;
+
+        listing.tokens_remaining -= tokens;
+
+        if listing.tokens_remaining == 0 {
+            let cpi_accounts = CloseAccount {
+                account: self.listing_vault.to_account_info(),
+                destination: self.seller.to_account_info(),
+                authority: listing.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::close_account(cpi_ctx)?;
+
+            listing.close(self.seller.to_account_info())?;
+            self.property.open_listings -= 1;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{CommitmentRound, Property, SaleMode};
+
+#[derive(Accounts)]
+pub struct FinalizeCommitmentRound<'info> {
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        mut,
+        seeds = [b"commitment_round", property.key().as_ref()],
+        bump = commitment_round.bump,
+    )]
+    pub commitment_round: Account<'info, CommitmentRound>,
+}
+
+impl<'info> FinalizeCommitmentRound<'info> {
+    /// Fixes the allocation once the commitment phase has closed and reopens
+    /// the fixed-price sale with whatever was not allocated. Allocated tokens
+    /// stay reserved until their commitments are claimed.
+    pub fn finalize_commitment_round(&mut self) -> Result<()> {
+        let property = &mut self.property;
+        let round = &mut self.commitment_round;
+
+        require!(
+            property.sale_mode == SaleMode::Commitment && !round.finalized,
+            crate::errors::Errors::CommitmentRoundNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= round.end_time,
+            crate::errors::Errors::CommitmentRoundNotEnded
+        );
+
+        round.finalize();
+
+        property.available_tokens = property
+            .available_tokens
+            .checked_add(round.tokens_offered - round.tokens_allocated())
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        property.sale_mode = SaleMode::FixedPrice;
+
+        Ok(())
+    }
+}
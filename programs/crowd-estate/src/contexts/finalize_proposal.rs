@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::{Property, Proposal};
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    pub property: Account<'info, Property>,
+
+    #[account(mut, has_one = property)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> FinalizeProposal<'info> {
+    pub fn finalize_proposal(&mut self) -> Result<()> {
+        self.proposal.finalize(&self.property)
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{OrderBook, Property};
+
+#[derive(Accounts)]
+pub struct InitializeOrderBook<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        has_one = admin,
+        constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty,
+    )]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = OrderBook::INIT_SPACE,
+        seeds = [b"order_book", property.key().as_ref()],
+        bump
+    )]
+    pub order_book: Account<'info, OrderBook>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = property_mint,
+        associated_token::authority = order_book,
+    )]
+    pub base_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = order_book,
+    )]
+    pub quote_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeOrderBook<'info> {
+    pub fn initialize_order_book(&mut self, bump: u8) -> Result<()> {
+        require!(!self.property.is_closed, crate::errors::Errors::PropertyClosed);
+
+        self.order_book.set_inner(OrderBook {
+            property: self.property.key(),
+            base_mint: self.property_mint.key(),
+            quote_mint: self.usdc_mint.key(),
+            base_vault: self.base_vault.key(),
+            quote_vault: self.quote_vault.key(),
+            next_order_id: 0,
+            bump,
+            unsettled_base: 0,
+            unsettled_quote: 0,
+            bids: Vec::new(),
+            asks: Vec::new(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{Pool, Property};
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        has_one = admin,
+        constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty,
+    )]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Pool::INIT_SPACE,
+        seeds = [b"pool", property.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = property_mint,
+        associated_token::authority = pool,
+    )]
+    pub base_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = pool,
+    )]
+    pub quote_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializePool<'info> {
+    pub fn initialize_pool(&mut self, fee_bps: u16, bump: u8) -> Result<()> {
+        require!(
+            !self.property.is_closed,
+            crate::errors::Errors::PropertyClosed
+        );
+        require!(fee_bps < 10_000, crate::errors::Errors::InvalidFee);
+
+        self.pool.set_inner(Pool {
+            property: self.property.key(),
+            base_mint: self.property_mint.key(),
+            quote_mint: self.usdc_mint.key(),
+            base_vault: self.base_vault.key(),
+            quote_vault: self.quote_vault.key(),
+            base_reserve: 0,
+            quote_reserve: 0,
+            total_shares: 0,
+            fee_bps,
+            dividend_debt: 0,
+            dividends_per_share: 0,
+            bump,
+        });
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
-use crate::state::{Investor, Property};
+use crate::state::{
+    mul_div, ComplianceRecord, InvestmentRecord, Investor, Property, ReferrerStats, SaleMode,
+};
 
 #[derive(Accounts)]
 pub struct InvestInProperty<'info> {
@@ -10,11 +12,14 @@ pub struct InvestInProperty<'info> {
     #[account(mut)]
     pub investor: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = investor_usdc_account.mint == property.quote_mint @ crate::errors::Errors::InvalidUsdcAccount,
+    )]
     pub investor_usdc_account: Account<'info, TokenAccount>,
 
     #[account(
-        init,
+        init_if_needed,
         seeds = [b"investment", investor.key().as_ref(), property.key().as_ref()],
         bump,
         payer = investor,
@@ -22,13 +27,42 @@ pub struct InvestInProperty<'info> {
     )]
     pub investment_account: Account<'info, Investor>,
 
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), investor.key().as_ref()],
+        bump,
+    )]
+    pub compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(
+        init,
+        seeds = [
+            b"investment_record",
+            property.key().as_ref(),
+            &property.investment_count.to_le_bytes(),
+        ],
+        bump,
+        payer = investor,
+        space = InvestmentRecord::INIT_SPACE,
+    )]
+    pub investment_record: Account<'info, InvestmentRecord>,
+
     #[account(mut)]
     pub property: Account<'info, Property>,
 
     #[account(mut)]
     pub property_mint: Account<'info, Mint>,
 
-    #[account(mut)]
+    #[account(address = property.quote_mint @ crate::errors::Errors::InvalidUsdcAccount)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = property,
+    )]
     pub property_usdc_account: Account<'info, TokenAccount>,
 
     #[account(
@@ -45,15 +79,41 @@ pub struct InvestInProperty<'info> {
     )]
     pub investor_property_token_account: Account<'info, TokenAccount>,
 
+    #[account(mut)]
+    pub platform_fee_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub referrer_stats: Option<Account<'info, ReferrerStats>>,
+
+    #[account(mut)]
+    pub referrer_usdc_account: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> InvestInProperty<'info> {
-    pub fn invest_in_property(&mut self, usdc_amount: u64) -> Result<()> {
+    pub fn invest_in_property(
+        &mut self,
+        tokens_to_purchase: u64,
+        max_token_price_usdc: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
         let property = &mut self.property;
 
-        let tokens_to_purchase = usdc_amount / property.token_price_usdc;
+        require!(!property.is_paused, crate::errors::Errors::PropertyPaused);
+        require!(
+            !property.is_liquidating,
+            crate::errors::Errors::PropertyLiquidating
+        );
+        require!(
+            property.sale_mode == SaleMode::FixedPrice,
+            crate::errors::Errors::InvalidSaleMode
+        );
+        require!(
+            Clock::get()?.slot <= expiry_slot,
+            crate::errors::Errors::QuoteExpired
+        );
         require!(
             tokens_to_purchase > 0,
             crate::errors::Errors::InsufficientAmount
@@ -64,6 +124,23 @@ impl<'info> InvestInProperty<'info> {
             crate::errors::Errors::NotEnoughTokens
         );
 
+        let (usdc_amount, tranche_fills) = property.quote_tokens(tokens_to_purchase)?;
+        let platform_fee = mul_div(
+            usdc_amount as u128,
+            property.platform_fee_bps as u128,
+            10_000,
+        )?;
+        // The limit covers everything the investor pays, fee included.
+        require!(
+            usdc_amount
+                .checked_add(platform_fee)
+                .ok_or(crate::errors::Errors::OverflowError)?
+                <= tokens_to_purchase
+                    .checked_mul(max_token_price_usdc)
+                    .ok_or(crate::errors::Errors::MultiplicationError)?,
+            crate::errors::Errors::PriceAboveLimit
+        );
+
         let cpi_accounts = Transfer {
             from: self.investor_usdc_account.to_account_info(),
             to: self.property_usdc_account.to_account_info(),
@@ -73,9 +150,98 @@ impl<'info> InvestInProperty<'info> {
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         token::transfer(cpi_ctx, usdc_amount)?;
 
+        // A referrer can only be attributed on the first investment.
+        let mut new_referral = false;
+        if let (Some(referrer_stats), None) =
+            (&self.referrer_stats, self.investment_account.referrer)
+        {
+            require!(
+                referrer_stats.referrer != self.investor.key(),
+                crate::errors::Errors::SelfReferral
+            );
+            require!(
+                self.investment_account.investor == Pubkey::default(),
+                crate::errors::Errors::InvalidReferrer
+            );
+            self.investment_account.referrer = Some(referrer_stats.referrer);
+            new_referral = true;
+        }
+
+        // The referrer's share goes to the platform whenever the referrer's
+        // accounts are missing or cannot take it, so a referral never blocks
+        // a purchase.
+        let attributed = match (&self.referrer_stats, self.investment_account.referrer) {
+            (Some(referrer_stats), Some(referrer)) => referrer_stats.referrer == referrer,
+            _ => false,
+        };
+        let referrer_usdc_account = match &self.referrer_stats {
+            Some(referrer_stats) if attributed => {
+                self.referrer_usdc_account.as_ref().filter(|account| {
+                    account.key() == referrer_stats.usdc_account
+                        && account.mint == property.quote_mint
+                        && !account.is_frozen()
+                })
+            }
+            _ => None,
+        };
+        let referral_fee = if referrer_usdc_account.is_some() {
+            mul_div(
+                platform_fee as u128,
+                property.referral_share_bps as u128,
+                10_000,
+            )?
+        } else {
+            0
+        };
+
+        if platform_fee > referral_fee {
+            let platform_fee_account = self
+                .platform_fee_account
+                .as_ref()
+                .ok_or(crate::errors::Errors::InvalidFeeAccount)?;
+            require!(
+                platform_fee_account.owner == property.fee_recipient,
+                crate::errors::Errors::InvalidFeeAccount
+            );
+
+            let cpi_accounts = Transfer {
+                from: self.investor_usdc_account.to_account_info(),
+                to: platform_fee_account.to_account_info(),
+                authority: self.investor.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+            token::transfer(cpi_ctx, platform_fee - referral_fee)?;
+        }
+
+        if let Some(referrer_usdc_account) = referrer_usdc_account {
+            if referral_fee > 0 {
+                let cpi_accounts = Transfer {
+                    from: self.investor_usdc_account.to_account_info(),
+                    to: referrer_usdc_account.to_account_info(),
+                    authority: self.investor.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+                token::transfer(cpi_ctx, referral_fee)?;
+            }
+        }
+
+        if let Some(referrer_stats) = self.referrer_stats.as_mut().filter(|_| attributed) {
+            if new_referral {
+                referrer_stats.referred_investors += 1;
+            }
+            referrer_stats.referred_volume_usdc = referrer_stats
+                .referred_volume_usdc
+                .checked_add(usdc_amount)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+            referrer_stats.fees_earned = referrer_stats
+                .fees_earned
+                .checked_add(referral_fee)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+        }
+
         let seeds = &[
             b"property",
-            property.admin.as_ref(),
+            property.creator.as_ref(),
             &property.property_name,
             &[property.bump],
         ];
@@ -94,14 +260,55 @@ impl<'info> InvestInProperty<'info> {
         token::transfer(cpi_ctx, tokens_to_purchase)?;
 
         property.available_tokens -= tokens_to_purchase;
+        for (tranche, fill) in property.price_tranches.iter_mut().zip(tranche_fills) {
+            tranche.tokens_sold += fill;
+        }
 
-        self.investment_account.set_inner(Investor {
+        let investment_account = &mut self.investment_account;
+
+        // A fresh account has no owner yet; an existing one settles what its
+        // current balance earned so the top-up only earns future dividends.
+        if investment_account.investor == Pubkey::default() {
+            investment_account.investor = self.investor.key();
+            investment_account.property = property.key();
+        }
+        self.compliance_record.register(
+            self.investor.key(),
+            property.key(),
+            property.default_withholding_rate_bps,
+        );
+        self.compliance_record.check_can_receive(property)?;
+        investment_account.add_tokens(tokens_to_purchase, usdc_amount, property)?;
+        require!(
+            property.max_tokens_per_investor == 0
+                || investment_account.tokens_owned <= property.max_tokens_per_investor,
+            crate::errors::Errors::InvestorLimitExceeded
+        );
+        for (bought, fill) in investment_account
+            .tranche_tokens
+            .iter_mut()
+            .zip(tranche_fills)
+        {
+            *bought = bought
+                .checked_add(fill)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+        }
+
+        self.investment_record.set_inner(InvestmentRecord {
             investor: self.investor.key(),
-            property: self.property.key(),
-            tokens_owned: tokens_to_purchase,
-            dividends_claimed: 0,
+            property: property.key(),
+            index: property.investment_count,
+            tokens: tokens_to_purchase,
+            token_price_usdc: usdc_amount / tokens_to_purchase,
+            usdc_amount,
+            timestamp: Clock::get()?.unix_timestamp,
         });
 
+        property.investment_count = property
+            .investment_count
+            .checked_add(1)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
         Ok(())
     }
 }
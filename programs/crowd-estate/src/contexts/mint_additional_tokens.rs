@@ -1,16 +1,23 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
 
-use crate::Property;
+use crate::{Property, RoleAssignment};
 
 #[derive(Accounts)]
 pub struct MintAdditionalTokens<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub treasurer: Signer<'info>,
 
     #[account(mut)]
     pub property: Account<'info, Property>,
 
+    #[account(
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+        has_one = treasurer,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+
     #[account(mut)]
     pub property_mint: Account<'info, Mint>,
 
@@ -29,14 +36,9 @@ impl<'info> MintAdditionalTokens<'info> {
     pub fn mint_additional_tokens(&mut self, amount: u64) -> Result<()> {
         let property = &mut self.property;
 
-        require!(
-            property.admin == self.admin.key(),
-            crate::errors::Errors::Unauthorized
-        );
-
         let seeds = &[
             b"property",
-            property.admin.as_ref(),
+            property.creator.as_ref(),
             &property.property_name,
             &[property.bump],
         ];
@@ -22,3 +22,95 @@ pub mod execute_proposal;
 pub use execute_proposal::*;
 pub mod update_property;
 pub use update_property::*;
+pub mod set_withholding_rate;
+pub use set_withholding_rate::*;
+pub mod set_default_withholding_rate;
+pub use set_default_withholding_rate::*;
+pub mod create_listing;
+pub use create_listing::*;
+pub mod fill_listing;
+pub use fill_listing::*;
+pub mod cancel_listing;
+pub use cancel_listing::*;
+pub mod initialize_order_book;
+pub use initialize_order_book::*;
+pub mod place_order;
+pub use place_order::*;
+pub mod cancel_order;
+pub use cancel_order::*;
+pub mod settle_order;
+pub use settle_order::*;
+pub mod initialize_pool;
+pub use initialize_pool::*;
+pub mod add_liquidity;
+pub use add_liquidity::*;
+pub mod remove_liquidity;
+pub use remove_liquidity::*;
+pub mod swap;
+pub use swap::*;
+pub mod claim_pool_dividends;
+pub use claim_pool_dividends::*;
+pub mod configure_dutch_auction;
+pub use configure_dutch_auction::*;
+pub mod place_auction_bid;
+pub use place_auction_bid::*;
+pub mod settle_auction_bid;
+pub use settle_auction_bid::*;
+pub mod set_price_tranches;
+pub use set_price_tranches::*;
+pub mod open_commitment_round;
+pub use open_commitment_round::*;
+pub mod commit_to_round;
+pub use commit_to_round::*;
+pub mod finalize_commitment_round;
+pub use finalize_commitment_round::*;
+pub mod claim_commitment;
+pub use claim_commitment::*;
+pub mod register_referrer;
+pub use register_referrer::*;
+pub mod set_platform_fee;
+pub use set_platform_fee::*;
+pub mod set_fee_recipients;
+pub use set_fee_recipients::*;
+pub mod set_governance_params;
+pub use set_governance_params::*;
+pub mod close_proposal;
+pub use close_proposal::*;
+pub mod cancel_proposal;
+pub use cancel_proposal::*;
+pub mod finalize_proposal;
+pub use finalize_proposal::*;
+pub mod veto_proposal;
+pub use veto_proposal::*;
+pub mod delegate_votes;
+pub use delegate_votes::*;
+pub mod revoke_delegation;
+pub use revoke_delegation::*;
+pub mod change_vote;
+pub use change_vote::*;
+pub mod retract_vote;
+pub use retract_vote::*;
+pub mod transfer_admin;
+pub use transfer_admin::*;
+pub mod accept_admin;
+pub use accept_admin::*;
+pub mod create_admin_council;
+pub use create_admin_council::*;
+pub mod propose_council_action;
+pub use propose_council_action::*;
+pub mod approve_council_action;
+pub use approve_council_action::*;
+pub mod execute_council_action;
+pub use execute_council_action::*;
+pub mod set_role;
+pub use set_role::*;
+pub mod set_wallet_compliance;
+pub use set_wallet_compliance::*;
+pub mod set_kyc_required;
+pub use set_kyc_required::*;
+pub mod set_paused;
+pub use set_paused::*;
+pub mod close_auction;
+pub use close_auction::*;
+pub mod update_admin_council;
+pub use update_admin_council::*;
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{CommitmentRound, Property, SaleMode};
+
+#[derive(Accounts)]
+pub struct OpenCommitmentRound<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut, has_one = admin)]
+    pub property: Account<'info, Property>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CommitmentRound::INIT_SPACE,
+        seeds = [b"commitment_round", property.key().as_ref()],
+        bump,
+    )]
+    pub commitment_round: Account<'info, CommitmentRound>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenCommitmentRound<'info> {
+    /// Reserves every available token for a commitment phase that runs
+    /// until `end_time` at the current token price. Investors on the
+    /// `priority_list` are allocated before everyone else.
+    pub fn open_commitment_round(
+        &mut self,
+        end_time: i64,
+        priority_list: Vec<Pubkey>,
+        bump: u8,
+    ) -> Result<()> {
+        let property = &mut self.property;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!property.is_closed, crate::errors::Errors::PropertyClosed);
+        require!(
+            property.sale_mode == SaleMode::FixedPrice
+                && property.dutch_auction.unsettled_bids == 0
+                && self.commitment_round.commitment_count == 0,
+            crate::errors::Errors::InvalidSaleMode
+        );
+        require!(
+            end_time > now && property.available_tokens > 0,
+            crate::errors::Errors::InvalidCommitmentRound
+        );
+        require!(
+            priority_list.len() <= CommitmentRound::MAX_PRIORITY_LIST,
+            crate::errors::Errors::InvalidCommitmentRound
+        );
+
+        self.commitment_round.set_inner(CommitmentRound {
+            property: property.key(),
+            token_price_usdc: property.token_price_usdc,
+            quote_mint: self.usdc_mint.key(),
+            tokens_offered: property.available_tokens,
+            start_time: now,
+            end_time,
+            tokens_committed: 0,
+            priority_tokens_committed: 0,
+            priority_tokens_allocated: 0,
+            general_tokens_allocated: 0,
+            tokens_claimed: 0,
+            commitment_count: 0,
+            finalized: false,
+            priority_list,
+            bump,
+        });
+
+        property.available_tokens = 0;
+        property.sale_mode = SaleMode::Commitment;
+
+        Ok(())
+    }
+}
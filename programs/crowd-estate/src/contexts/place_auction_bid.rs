@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{AuctionBid, ComplianceRecord, Property, SaleMode};
+
+#[derive(Accounts)]
+pub struct PlaceAuctionBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = AuctionBid::INIT_SPACE,
+        seeds = [b"auction_bid", property.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub auction_bid: Account<'info, AuctionBid>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(mut)]
+    pub bidder_usdc_account: Account<'info, TokenAccount>,
+
+    #[account(address = property.dutch_auction.quote_mint @ crate::errors::Errors::InvalidUsdcAccount)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = property,
+    )]
+    pub property_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PlaceAuctionBid<'info> {
+    /// Commits USDC for `tokens` at the current auction price. The bid is
+    /// trimmed to what is left, and the auction clears once nothing is left.
+    pub fn place_auction_bid(&mut self, tokens: u64, max_price_usdc: u64) -> Result<()> {
+        let property = &mut self.property;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!property.is_paused, crate::errors::Errors::PropertyPaused);
+        require!(
+            property.sale_mode == SaleMode::DutchAuction,
+            crate::errors::Errors::InvalidSaleMode
+        );
+        require!(
+            property.dutch_auction.clearing_price == 0
+                && now >= property.dutch_auction.start_time
+                && now < property.dutch_auction.end_time(),
+            crate::errors::Errors::AuctionNotActive
+        );
+
+        self.compliance_record.register(
+            self.bidder.key(),
+            property.key(),
+            property.default_withholding_rate_bps,
+        );
+        self.compliance_record.check_can_receive(property)?;
+
+        let price = property.dutch_auction.price_at(now);
+        require!(
+            price <= max_price_usdc,
+            crate::errors::Errors::PriceAboveLimit
+        );
+
+        let tokens = tokens.min(property.available_tokens);
+        require!(tokens > 0, crate::errors::Errors::InsufficientAmount);
+
+        let usdc_amount = tokens
+            .checked_mul(price)
+            .ok_or(crate::errors::Errors::MultiplicationError)?;
+
+        let cpi_accounts = Transfer {
+            from: self.bidder_usdc_account.to_account_info(),
+            to: self.property_usdc_account.to_account_info(),
+            authority: self.bidder.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, usdc_amount)?;
+
+        property.sale_escrow_held = property
+            .sale_escrow_held
+            .checked_add(usdc_amount)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        let auction_bid = &mut self.auction_bid;
+        if auction_bid.bidder == Pubkey::default() {
+            auction_bid.bidder = self.bidder.key();
+            auction_bid.property = property.key();
+            property.dutch_auction.unsettled_bids = property
+                .dutch_auction
+                .unsettled_bids
+                .checked_add(1)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+        }
+        auction_bid.tokens = auction_bid
+            .tokens
+            .checked_add(tokens)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        auction_bid.usdc_paid = auction_bid
+            .usdc_paid
+            .checked_add(usdc_amount)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        property.available_tokens -= tokens;
+        if property.available_tokens == 0 {
+            property.dutch_auction.clearing_price = price;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,371 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{ComplianceRecord, Investor, Order, OrderBook, OrderSide, Property, TradeEvent};
+
+#[derive(Accounts)]
+pub struct PlaceOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty)]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = property,
+        seeds = [b"order_book", property.key().as_ref()],
+        bump = order_book.bump,
+    )]
+    pub order_book: Account<'info, OrderBook>,
+
+    #[account(mut, address = order_book.base_vault)]
+    pub base_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, address = order_book.quote_vault)]
+    pub quote_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = Investor::INIT_SPACE,
+        seeds = [b"investment", owner.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub owner_investment_account: Account<'info, Investor>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub owner_compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_property_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = owner_usdc_account.owner == owner.key() @ crate::errors::Errors::InvalidUsdcAccount,
+        constraint = owner_usdc_account.mint == order_book.quote_mint @ crate::errors::Errors::InvalidUsdcAccount,
+    )]
+    pub owner_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PlaceOrder<'info> {
+    /// `maker_accounts` must hold the `Investor` and `ComplianceRecord` accounts
+    /// of every maker the order will match against, so their positions move
+    /// with the trade. Orders whose maker's account no longer exists are passed
+    /// over. Fills are credited to the maker's settlement balance and filled
+    /// orders leave the book.
+    pub fn place_order(
+        &mut self,
+        side: OrderSide,
+        price: u64,
+        quantity: u64,
+        maker_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(!self.property.is_closed, crate::errors::Errors::PropertyClosed);
+        require!(!self.property.is_paused, crate::errors::Errors::PropertyPaused);
+        require!(
+            !self.property.is_liquidating,
+            crate::errors::Errors::PropertyLiquidating
+        );
+        require!(price > 0, crate::errors::Errors::InvalidTokenPrice);
+        require!(quantity > 0, crate::errors::Errors::InsufficientAmount);
+        let order_value = quantity
+            .checked_mul(price)
+            .ok_or(crate::errors::Errors::MultiplicationError)?;
+        require!(
+            order_value >= OrderBook::MIN_ORDER_VALUE,
+            crate::errors::Errors::OrderTooSmall
+        );
+
+        let owner = self.owner.key();
+        let property_key = self.property.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        let owner_investment_account = &mut self.owner_investment_account;
+        if owner_investment_account.investor == Pubkey::default() {
+            owner_investment_account.investor = owner;
+            owner_investment_account.property = property_key;
+        }
+        self.owner_compliance_record.register(
+            owner,
+            property_key,
+            self.property.default_withholding_rate_bps,
+        );
+        match side {
+            OrderSide::Ask => self.owner_compliance_record.check_can_send(timestamp)?,
+            OrderSide::Bid => self
+                .owner_compliance_record
+                .check_can_receive(&self.property)?,
+        }
+
+        // Lock what the order can spend: tokens for an ask, USDC at the limit price for a bid.
+        match side {
+            OrderSide::Ask => {
+                require!(
+                    owner_investment_account.unlisted_tokens() >= quantity,
+                    crate::errors::Errors::NotEnoughTokens
+                );
+
+                let cpi_accounts = Transfer {
+                    from: self.owner_property_token_account.to_account_info(),
+                    to: self.base_vault.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+                token::transfer(cpi_ctx, quantity)?;
+
+                owner_investment_account.tokens_listed = owner_investment_account
+                    .tokens_listed
+                    .checked_add(quantity)
+                    .ok_or(crate::errors::Errors::OverflowError)?;
+            }
+            OrderSide::Bid => {
+                let cpi_accounts = Transfer {
+                    from: self.owner_usdc_account.to_account_info(),
+                    to: self.quote_vault.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+                token::transfer(cpi_ctx, order_value)?;
+            }
+        }
+
+        // Trading against its own resting orders would leave the book crossed.
+        require!(
+            !self.order_book.crosses_own_order(side, price, &owner),
+            crate::errors::Errors::SelfCrossingOrder
+        );
+
+        let mut makers: Vec<(Account<'info, Investor>, bool)> = Vec::new();
+        let mut passed_over: Vec<u64> = Vec::new();
+        let mut remaining = quantity;
+        let mut base_filled: u64 = 0;
+        let mut quote_filled: u64 = 0;
+
+        while remaining > 0 {
+            let Some(index) = self.order_book.best_match(side, price, &passed_over) else {
+                break;
+            };
+            let maker_order = self.order_book.orders(side.opposite())[index].clone();
+
+            let maker_position = match makers
+                .iter()
+                .position(|(account, _)| account.investor == maker_order.owner)
+            {
+                Some(position) => position,
+                None => {
+                    let info = find_maker_account(
+                        maker_accounts,
+                        &[
+                            b"investment",
+                            maker_order.owner.as_ref(),
+                            property_key.as_ref(),
+                        ],
+                    )?;
+                    // The maker closed its position and cannot take delivery;
+                    // its order stays on the book for it to cancel.
+                    if info.owner != &crate::ID || info.data_is_empty() {
+                        passed_over.push(maker_order.order_id);
+                        continue;
+                    }
+                    let compliance_info = find_maker_account(
+                        maker_accounts,
+                        &[
+                            b"compliance",
+                            property_key.as_ref(),
+                            maker_order.owner.as_ref(),
+                        ],
+                    )?;
+                    let compliance_record = Account::<ComplianceRecord>::try_from(compliance_info)?;
+                    let compliant = match side {
+                        OrderSide::Bid => compliance_record.check_can_send(timestamp),
+                        OrderSide::Ask => compliance_record.check_can_receive(&self.property),
+                    }
+                    .is_ok();
+                    makers.push((Account::<Investor>::try_from(info)?, compliant));
+                    makers.len() - 1
+                }
+            };
+            let (maker_investment_account, compliant) = &mut makers[maker_position];
+
+            // A maker that may no longer trade is taken off the book, its lock
+            // released to its settlement balance, instead of blocking the taker.
+            if !*compliant {
+                let (base, quote) = match side {
+                    OrderSide::Bid => {
+                        maker_investment_account.tokens_listed -= maker_order.quantity;
+                        (maker_order.quantity, 0)
+                    }
+                    OrderSide::Ask => (
+                        0,
+                        maker_order
+                            .quantity
+                            .checked_mul(maker_order.price)
+                            .ok_or(crate::errors::Errors::MultiplicationError)?,
+                    ),
+                };
+                self.order_book
+                    .credit(maker_investment_account, base, quote)?;
+                self.order_book.orders_mut(side.opposite())[index].quantity = 0;
+                continue;
+            }
+
+            let fill = remaining.min(maker_order.quantity);
+            let fill_value = fill
+                .checked_mul(maker_order.price)
+                .ok_or(crate::errors::Errors::MultiplicationError)?;
+
+            self.order_book.orders_mut(side.opposite())[index].quantity -= fill;
+            match side {
+                OrderSide::Bid => {
+                    self.order_book
+                        .credit(maker_investment_account, 0, fill_value)?;
+
+                    maker_investment_account.remove_tokens(fill, &self.property)?;
+                    maker_investment_account.tokens_listed -= fill;
+                    owner_investment_account.add_tokens(fill, fill_value, &self.property)?;
+                }
+                OrderSide::Ask => {
+                    self.order_book
+                        .credit(maker_investment_account, fill, 0)?;
+
+                    owner_investment_account.remove_tokens(fill, &self.property)?;
+                    owner_investment_account.tokens_listed -= fill;
+                    maker_investment_account.add_tokens(fill, fill_value, &self.property)?;
+                }
+            }
+
+            emit!(TradeEvent {
+                property: property_key,
+                maker_order_id: maker_order.order_id,
+                maker: maker_order.owner,
+                taker: owner,
+                taker_side: side,
+                price: maker_order.price,
+                quantity: fill,
+                timestamp,
+            });
+
+            remaining -= fill;
+            base_filled += fill;
+            quote_filled = quote_filled
+                .checked_add(fill_value)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+        }
+
+        // Filled orders free their slot; what they are owed is now held on
+        // their owner's position.
+        self.order_book
+            .orders_mut(side.opposite())
+            .retain(|order| order.quantity > 0);
+        for (maker, _) in makers.iter() {
+            maker.exit(&crate::ID)?;
+        }
+
+        let seeds = &[
+            b"order_book",
+            property_key.as_ref(),
+            &[self.order_book.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Pay the taker side of every fill, refunding a bid's price improvement.
+        let (payout_from, payout_to, payout) = match side {
+            OrderSide::Bid => (
+                &self.base_vault,
+                self.owner_property_token_account.to_account_info(),
+                base_filled,
+            ),
+            OrderSide::Ask => (
+                &self.quote_vault,
+                self.owner_usdc_account.to_account_info(),
+                quote_filled,
+            ),
+        };
+        if payout > 0 {
+            let cpi_accounts = Transfer {
+                from: payout_from.to_account_info(),
+                to: payout_to,
+                authority: self.order_book.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, payout)?;
+        }
+
+        if side == OrderSide::Bid {
+            let price_improvement = base_filled
+                .checked_mul(price)
+                .ok_or(crate::errors::Errors::MultiplicationError)?
+                - quote_filled;
+
+            if price_improvement > 0 {
+                let cpi_accounts = Transfer {
+                    from: self.quote_vault.to_account_info(),
+                    to: self.owner_usdc_account.to_account_info(),
+                    authority: self.order_book.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds,
+                );
+                token::transfer(cpi_ctx, price_improvement)?;
+            }
+        }
+
+        if remaining > 0 {
+            let order_book = &mut self.order_book;
+            let order_id = order_book.next_order_id;
+            let orders = order_book.orders_mut(side);
+            require!(
+                orders.len() < OrderBook::MAX_ORDERS_PER_SIDE,
+                crate::errors::Errors::OrderBookFull
+            );
+
+            orders.push(Order {
+                order_id,
+                owner,
+                price,
+                quantity: remaining,
+                timestamp,
+            });
+            order_book.next_order_id = order_id
+                .checked_add(1)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The account in `maker_accounts` at the program address derived from `seeds`.
+fn find_maker_account<'info>(
+    maker_accounts: &'info [AccountInfo<'info>],
+    seeds: &[&[u8]],
+) -> Result<&'info AccountInfo<'info>> {
+    let (address, _) = Pubkey::find_program_address(seeds, &crate::ID);
+
+    maker_accounts
+        .iter()
+        .find(|info| info.key() == address)
+        .ok_or(crate::errors::Errors::MissingMakerAccount.into())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{AdminCouncil, CouncilAction, CouncilActionKind};
+
+#[derive(Accounts)]
+pub struct ProposeCouncilAction<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(mut)]
+    pub council: Account<'info, AdminCouncil>,
+
+    #[account(
+        init,
+        payer = member,
+        space = CouncilAction::INIT_SPACE,
+        seeds = [
+            b"council_action",
+            council.key().as_ref(),
+            &council.action_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub council_action: Account<'info, CouncilAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeCouncilAction<'info> {
+    /// Opens an action for approval, counting the proposer's approval.
+    pub fn propose_council_action(&mut self, action: CouncilActionKind) -> Result<()> {
+        let member = self.member.key();
+        require!(
+            self.council.is_member(&member),
+            crate::errors::Errors::NotCouncilMember
+        );
+        action.validate()?;
+
+        let council = &mut self.council;
+        let index = council.action_count;
+        council.action_count = index
+            .checked_add(1)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        self.council_action.set_inner(CouncilAction {
+            council: council.key(),
+            index,
+            proposer: member,
+            action,
+            approvals: vec![member],
+            executed: false,
+        });
+
+        Ok(())
+    }
+}
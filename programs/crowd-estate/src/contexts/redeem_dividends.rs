@@ -1,54 +1,100 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
-use crate::{Investor, Property};
+use crate::{ComplianceRecord, Investor, Property, TaxRecord};
 
 #[derive(Accounts)]
+#[instruction(year: u16)]
 pub struct RedeemDividends<'info> {
     #[account(mut)]
     pub investor: Signer<'info>,
 
-    #[account(mut)]
+    #[account(address = property.quote_mint @ crate::errors::Errors::InvalidUsdcAccount)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = property,
+    )]
     pub property_usdc_account: Account<'info, TokenAccount>,
 
     #[account(mut)]
     pub investor_usdc_account: Account<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        constraint = tax_vault.owner == property.tax_recipient @ crate::errors::Errors::InvalidTaxVault,
+        constraint = tax_vault.mint == property_usdc_account.mint @ crate::errors::Errors::InvalidTaxVault,
+    )]
+    pub tax_vault: Account<'info, TokenAccount>,
+
     #[account(mut)]
     pub property: Account<'info, Property>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = investor,
+        has_one = property,
+    )]
     pub investment_account: Account<'info, Investor>,
 
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), investor.key().as_ref()],
+        bump,
+    )]
+    pub compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = TaxRecord::INIT_SPACE,
+        seeds = [b"tax_record", investment_account.key().as_ref(), &year.to_le_bytes()],
+        bump
+    )]
+    pub tax_record: Account<'info, TaxRecord>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 
 impl<'info> RedeemDividends<'info> {
-    pub fn redeem_dividends(&mut self) -> Result<()> {
+    pub fn redeem_dividends(&mut self, year: u16) -> Result<()> {
         let property = &self.property;
         let investor_account = &mut self.investment_account;
 
-        let dividend_per_token = property
-            .dividends_total
-            .checked_div(property.total_tokens)
-            .ok_or(crate::errors::Errors::DivisionError)?;
+        let compliance_record = &mut self.compliance_record;
+        compliance_record.register(
+            investor_account.investor,
+            property.key(),
+            property.default_withholding_rate_bps,
+        );
+        let withholding_rate_bps = compliance_record.withholding_rate_bps;
 
-        let total_dividends_due = investor_account
-            .tokens_owned
-            .checked_mul(dividend_per_token)
-            .ok_or(crate::errors::Errors::MultiplicationError)?;
+        require!(
+            year == TaxRecord::year_from_timestamp(Clock::get()?.unix_timestamp),
+            crate::errors::Errors::InvalidTaxYear
+        );
 
-        let dividends_to_claim = total_dividends_due
-            .checked_sub(investor_account.dividends_claimed)
-            .ok_or(crate::errors::Errors::InvalidDividendsClaim)?;
+        investor_account.settle_dividends(property.dividends_per_token)?;
+        let dividends_to_claim = investor_account.dividends_pending;
 
         require!(dividends_to_claim > 0, crate::errors::Errors::NoDividendsToClaim);
 
+        let tax_withheld = (dividends_to_claim as u128)
+            .checked_mul(withholding_rate_bps as u128)
+            .ok_or(crate::errors::Errors::MultiplicationError)?
+            .checked_div(10_000)
+            .ok_or(crate::errors::Errors::DivisionError)? as u64;
+        let net_dividends = dividends_to_claim - tax_withheld;
+
         let seeds = &[
             b"property",
-            property.admin.as_ref(),
+            property.creator.as_ref(),
             &property.property_name,
             &[property.bump],
         ];
@@ -61,8 +107,45 @@ impl<'info> RedeemDividends<'info> {
         };
         let cpi_program = self.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        token::transfer(cpi_ctx, dividends_to_claim)?;
+        token::transfer(cpi_ctx, net_dividends)?;
+
+        if tax_withheld > 0 {
+            let cpi_accounts = Transfer {
+                from: self.property_usdc_account.to_account_info(),
+                to: self.tax_vault.to_account_info(),
+                authority: self.property.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, tax_withheld)?;
+        }
+
+        let tax_record = &mut self.tax_record;
+        tax_record.investor = investor_account.investor;
+        tax_record.property = investor_account.property;
+        tax_record.year = year;
+        tax_record.withholding_rate_bps = withholding_rate_bps;
+        tax_record.gross_dividends = tax_record
+            .gross_dividends
+            .checked_add(dividends_to_claim)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        tax_record.tax_withheld = tax_record
+            .tax_withheld
+            .checked_add(tax_withheld)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        tax_record.net_dividends = tax_record
+            .net_dividends
+            .checked_add(net_dividends)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        tax_record.withholding_count = tax_record
+            .withholding_count
+            .checked_add(1)
+            .ok_or(crate::errors::Errors::OverflowError)?;
 
+        investor_account.dividends_pending = 0;
         investor_account.dividends_claimed = investor_account
             .dividends_claimed
             .checked_add(dividends_to_claim)
@@ -73,6 +156,11 @@ impl<'info> RedeemDividends<'info> {
             .checked_sub(dividends_to_claim)
             .ok_or(crate::errors::Errors::OverflowError)?;
 
+        if self.investment_account.tokens_owned == 0 {
+            self.investment_account
+                .close(self.investor.to_account_info())?;
+        }
+
         Ok(())
     }
-}
\ No newline at end of file
+}
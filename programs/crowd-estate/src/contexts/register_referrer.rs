@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::ReferrerStats;
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = referrer,
+        space = ReferrerStats::INIT_SPACE,
+        seeds = [b"referrer", referrer.key().as_ref()],
+        bump,
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+
+    #[account(constraint = referrer_usdc_account.owner == referrer.key() @ crate::errors::Errors::InvalidUsdcAccount)]
+    pub referrer_usdc_account: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterReferrer<'info> {
+    /// Creates the referrer's stats account, or points an existing one at a
+    /// new USDC account for referral payouts.
+    pub fn register_referrer(&mut self, bump: u8) -> Result<()> {
+        let referrer_stats = &mut self.referrer_stats;
+
+        referrer_stats.referrer = self.referrer.key();
+        referrer_stats.usdc_account = self.referrer_usdc_account.key();
+        referrer_stats.bump = bump;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{mul_div, ComplianceRecord, Investor, LpPosition, Pool, Property};
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: only identifies the position's owner, checked against `lp_position`.
+    pub provider: UncheckedAccount<'info>,
+
+    #[account(constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty)]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = property,
+        seeds = [b"pool", property.key().as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, address = pool.base_vault)]
+    pub base_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, address = pool.quote_vault)]
+    pub quote_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Investor::INIT_SPACE,
+        seeds = [b"investment", provider.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub provider_investment_account: Account<'info, Investor>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), provider.key().as_ref()],
+        bump,
+    )]
+    pub provider_compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(
+        mut,
+        has_one = pool,
+        constraint = lp_position.owner == provider.key() @ crate::errors::Errors::Unauthorized,
+    )]
+    pub lp_position: Account<'info, LpPosition>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_property_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = provider_usdc_account.owner == provider.key() @ crate::errors::Errors::InvalidUsdcAccount,
+        constraint = provider_usdc_account.mint == pool.quote_mint @ crate::errors::Errors::InvalidUsdcAccount,
+    )]
+    pub provider_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RemoveLiquidity<'info> {
+    /// Anyone may remove a position once the property is liquidating, so
+    /// leftover liquidity cannot keep the property from closing.
+    pub fn remove_liquidity(
+        &mut self,
+        shares: u64,
+        min_base_amount: u64,
+        min_quote_amount: u64,
+    ) -> Result<()> {
+        require!(
+            !self.property.is_paused,
+            crate::errors::Errors::PropertyPaused
+        );
+        require!(
+            self.payer.key() == self.provider.key() || self.property.is_liquidating,
+            crate::errors::Errors::Unauthorized
+        );
+        require!(shares > 0, crate::errors::Errors::InsufficientAmount);
+        require!(
+            self.lp_position.shares >= shares,
+            crate::errors::Errors::NotEnoughTokens
+        );
+
+        let dividends_per_token = self.property.dividends_per_token;
+        let pool = &mut self.pool;
+        let lp_position = &mut self.lp_position;
+
+        pool.sync_dividends(dividends_per_token)?;
+        lp_position.settle_dividends(pool.dividends_per_share)?;
+
+        let base_amount = mul_div(
+            shares as u128,
+            pool.base_reserve as u128,
+            pool.total_shares as u128,
+        )?;
+        let quote_amount = mul_div(
+            shares as u128,
+            pool.quote_reserve as u128,
+            pool.total_shares as u128,
+        )?;
+        require!(
+            base_amount >= min_base_amount && quote_amount >= min_quote_amount,
+            crate::errors::Errors::SlippageExceeded
+        );
+
+        pool.base_reserve -= base_amount;
+        pool.quote_reserve -= quote_amount;
+        pool.total_shares -= shares;
+        pool.reset_dividend_debt(dividends_per_token)?;
+
+        lp_position.shares -= shares;
+        lp_position.reset_dividend_debt(pool.dividends_per_share)?;
+
+        let provider_investment_account = &mut self.provider_investment_account;
+        if provider_investment_account.investor == Pubkey::default() {
+            provider_investment_account.investor = self.provider.key();
+            provider_investment_account.property = self.property.key();
+        }
+        self.provider_compliance_record.register(
+            self.provider.key(),
+            self.property.key(),
+            self.property.default_withholding_rate_bps,
+        );
+        // A liquidating property's positions unwind regardless, so the
+        // tokens can be redeemed at the liquidation price.
+        if !self.property.is_liquidating {
+            self.provider_compliance_record
+                .check_can_receive(&self.property)?;
+        }
+        // The tokens are valued at the pool price they come out at.
+        provider_investment_account.add_tokens(base_amount, quote_amount, &self.property)?;
+
+        let property_key = self.property.key();
+        let seeds = &[b"pool", property_key.as_ref(), &[pool.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: self.base_vault.to_account_info(),
+            to: self.provider_property_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, base_amount)?;
+
+        let cpi_accounts = Transfer {
+            from: self.quote_vault.to_account_info(),
+            to: self.provider_usdc_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, quote_amount)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{Proposal, VoteRecord};
+
+#[derive(Accounts)]
+pub struct RetractVote<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        has_one = proposal,
+        constraint = authority.key() == vote_record.voter
+            || authority.key() == vote_record.cast_by @ crate::errors::Errors::Unauthorized,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+impl<'info> RetractVote<'info> {
+    /// Removes the record's weight from the tally. The holder may vote again
+    /// while the proposal is still open.
+    pub fn retract_vote(&mut self) -> Result<()> {
+        let proposal = &mut self.proposal;
+        let vote_record = &mut self.vote_record;
+
+        require!(
+            proposal.is_voting_open(Clock::get()?.unix_timestamp),
+            crate::errors::Errors::VotingClosed
+        );
+
+        let previous = vote_record
+            .choice
+            .ok_or(crate::errors::Errors::NoVoteToChange)?;
+        proposal.remove_votes(previous, vote_record.weight)?;
+
+        vote_record.choice = None;
+        vote_record.weight = 0;
+        vote_record.cast_by = self.authority.key();
+
+        Ok(())
+    }
+}
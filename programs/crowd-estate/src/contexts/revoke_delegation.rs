@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::Delegation;
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    #[account(mut, close = delegator, has_one = delegator)]
+    pub delegation: Account<'info, Delegation>,
+}
+
+impl<'info> RevokeDelegation<'info> {
+    /// Votes the delegate already cast on the delegator's behalf stand.
+    pub fn revoke_delegation(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{Property, RoleAssignment};
+
+#[derive(Accounts)]
+pub struct SetDefaultWithholdingRate<'info> {
+    pub compliance: Signer<'info>,
+
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+        has_one = compliance,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+}
+
+impl<'info> SetDefaultWithholdingRate<'info> {
+    /// Rate for wallets the compliance role has not classified. It applies
+    /// to their records as they are created; existing records keep theirs.
+    pub fn set_default_withholding_rate(&mut self, withholding_rate_bps: u16) -> Result<()> {
+        require!(
+            withholding_rate_bps <= 10_000,
+            crate::errors::Errors::InvalidWithholdingRate
+        );
+
+        self.property.default_withholding_rate_bps = withholding_rate_bps;
+
+        Ok(())
+    }
+}
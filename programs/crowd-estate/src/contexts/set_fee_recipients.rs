@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::Property;
+
+#[derive(Accounts)]
+pub struct SetFeeRecipients<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut, has_one = admin)]
+    pub property: Account<'info, Property>,
+}
+
+impl<'info> SetFeeRecipients<'info> {
+    /// Platform fees are paid to `fee_recipient`'s USDC account and withheld
+    /// tax to `tax_recipient`'s. Both stay put when the admin or a role changes
+    /// hands, so a council taking over does not strand them in its own PDA.
+    pub fn set_fee_recipients(
+        &mut self,
+        fee_recipient: Pubkey,
+        tax_recipient: Pubkey,
+    ) -> Result<()> {
+        require!(
+            fee_recipient != Pubkey::default() && tax_recipient != Pubkey::default(),
+            crate::errors::Errors::InvalidFeeAccount
+        );
+
+        self.property.fee_recipient = fee_recipient;
+        self.property.tax_recipient = tax_recipient;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{GovernanceParams, Property};
+
+#[derive(Accounts)]
+pub struct SetGovernanceParams<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut, has_one = admin)]
+    pub property: Account<'info, Property>,
+}
+
+impl<'info> SetGovernanceParams<'info> {
+    /// Applies to proposals created from now on; open proposals keep their
+    /// voting window.
+    pub fn set_governance_params(&mut self, governance: GovernanceParams) -> Result<()> {
+        governance.validate()?;
+
+        self.property.governance = governance;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{Property, RoleAssignment};
+
+#[derive(Accounts)]
+pub struct SetKycRequired<'info> {
+    pub compliance: Signer<'info>,
+
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+        has_one = compliance,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+}
+
+impl<'info> SetKycRequired<'info> {
+    /// When set, only KYC-verified wallets can take on the property's tokens.
+    pub fn set_kyc_required(&mut self, kyc_required: bool) -> Result<()> {
+        self.property.kyc_required = kyc_required;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{Property, RoleAssignment};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+        has_one = guardian,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+}
+
+impl<'info> SetPaused<'info> {
+    /// Stops investing, trading, transfers and withdrawals while paused.
+    /// Cancellations, claims and dividend redemptions keep working.
+    pub fn set_paused(&mut self, paused: bool) -> Result<()> {
+        self.property.is_paused = paused;
+
+        Ok(())
+    }
+}
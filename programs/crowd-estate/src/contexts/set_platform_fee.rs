@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::Property;
+
+#[derive(Accounts)]
+pub struct SetPlatformFee<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut, has_one = admin)]
+    pub property: Account<'info, Property>,
+}
+
+impl<'info> SetPlatformFee<'info> {
+    /// `platform_fee_bps` is charged on top of every primary purchase;
+    /// `referral_share_bps` of that fee goes to the investor's referrer.
+    pub fn set_platform_fee(
+        &mut self,
+        platform_fee_bps: u16,
+        referral_share_bps: u16,
+    ) -> Result<()> {
+        require!(
+            platform_fee_bps <= 10_000 && referral_share_bps <= 10_000,
+            crate::errors::Errors::InvalidFee
+        );
+
+        self.property.platform_fee_bps = platform_fee_bps;
+        self.property.referral_share_bps = referral_share_bps;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{PriceTranche, Property, SaleMode};
+
+#[derive(Accounts)]
+pub struct SetPriceTranches<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut, has_one = admin)]
+    pub property: Account<'info, Property>,
+}
+
+impl<'info> SetPriceTranches<'info> {
+    /// Replaces the tranche schedule. `tranches` are `(tokens, price_usdc)`
+    /// pairs, filled in order before `token_price_usdc` applies.
+    pub fn set_price_tranches(&mut self, tranches: Vec<(u64, u64)>) -> Result<()> {
+        let property = &mut self.property;
+
+        require!(!property.is_closed, crate::errors::Errors::PropertyClosed);
+        require!(
+            property.sale_mode == SaleMode::FixedPrice,
+            crate::errors::Errors::InvalidSaleMode
+        );
+        require!(
+            property
+                .price_tranches
+                .iter()
+                .all(|tranche| tranche.tokens_sold == 0),
+            crate::errors::Errors::InvalidPriceTranches
+        );
+        require!(
+            tranches.len() <= Property::MAX_PRICE_TRANCHES,
+            crate::errors::Errors::InvalidPriceTranches
+        );
+        require!(
+            tranches
+                .iter()
+                .all(|(tokens, price_usdc)| *tokens > 0 && *price_usdc > 0),
+            crate::errors::Errors::InvalidPriceTranches
+        );
+
+        let tranche_tokens = tranches
+            .iter()
+            .try_fold(0u64, |total, (tokens, _)| total.checked_add(*tokens))
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        require!(
+            tranche_tokens <= property.available_tokens,
+            crate::errors::Errors::InvalidPriceTranches
+        );
+
+        property.price_tranches = tranches
+            .into_iter()
+            .map(|(tokens, price_usdc)| PriceTranche {
+                tokens,
+                price_usdc,
+                tokens_sold: 0,
+            })
+            .collect();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{Property, Role, RoleAssignment};
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(has_one = admin)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        mut,
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+}
+
+impl<'info> SetRole<'info> {
+    pub fn set_role(&mut self, role: Role, holder: Pubkey) -> Result<()> {
+        require!(
+            holder != Pubkey::default(),
+            crate::errors::Errors::InvalidRoleHolder
+        );
+
+        *self.roles.holder_mut(role) = holder;
+
+        Ok(())
+    }
+}
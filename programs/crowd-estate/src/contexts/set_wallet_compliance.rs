@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{ComplianceRecord, Property, RoleAssignment};
+
+#[derive(Accounts)]
+pub struct SetWalletCompliance<'info> {
+    pub compliance: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub property: Account<'info, Property>,
+
+    #[account(
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+        has_one = compliance,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+
+    pub wallet: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), wallet.key().as_ref()],
+        bump,
+    )]
+    pub compliance_record: Account<'info, ComplianceRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetWalletCompliance<'info> {
+    /// Records `wallet`'s KYC outcome, freezes or unfreezes it, and locks its
+    /// tokens against leaving its position until `locked_until`.
+    pub fn set_wallet_compliance(
+        &mut self,
+        kyc_verified: bool,
+        frozen: bool,
+        locked_until: i64,
+    ) -> Result<()> {
+        let compliance_record = &mut self.compliance_record;
+        compliance_record.register(
+            self.wallet.key(),
+            self.property.key(),
+            self.property.default_withholding_rate_bps,
+        );
+        compliance_record.kyc_verified = kyc_verified;
+        compliance_record.frozen = frozen;
+        compliance_record.locked_until = locked_until;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{ComplianceRecord, Property, RoleAssignment};
+
+#[derive(Accounts)]
+pub struct SetWithholdingRate<'info> {
+    pub compliance: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub property: Account<'info, Property>,
+
+    #[account(
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+        has_one = compliance,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+
+    pub wallet: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), wallet.key().as_ref()],
+        bump,
+    )]
+    pub compliance_record: Account<'info, ComplianceRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetWithholdingRate<'info> {
+    /// Sets the rate withheld from `wallet`'s dividends, whether or not it
+    /// holds a position yet.
+    pub fn set_withholding_rate(&mut self, withholding_rate_bps: u16) -> Result<()> {
+        require!(
+            withholding_rate_bps <= 10_000,
+            crate::errors::Errors::InvalidWithholdingRate
+        );
+
+        let compliance_record = &mut self.compliance_record;
+        compliance_record.register(
+            self.wallet.key(),
+            self.property.key(),
+            self.property.default_withholding_rate_bps,
+        );
+        compliance_record.withholding_rate_bps = withholding_rate_bps;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{AuctionBid, ComplianceRecord, Investor, Property, SaleMode};
+
+#[derive(Accounts)]
+pub struct SettleAuctionBid<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The bidding wallet; pinned by `has_one` on the bid.
+    #[account(mut)]
+    pub bidder: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        mut,
+        close = bidder,
+        has_one = bidder,
+        has_one = property,
+    )]
+    pub auction_bid: Account<'info, AuctionBid>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Investor::INIT_SPACE,
+        seeds = [b"investment", bidder.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub investment_account: Account<'info, Investor>,
+
+    #[account(
+        seeds = [b"compliance", property.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(mut, constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty)]
+    pub property_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = property,
+    )]
+    pub property_vault: Account<'info, TokenAccount>,
+
+    #[account(address = property.dutch_auction.quote_mint @ crate::errors::Errors::InvalidUsdcAccount)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = property,
+    )]
+    pub property_usdc_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = property_mint,
+        associated_token::authority = bidder,
+    )]
+    pub bidder_property_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = bidder,
+    )]
+    pub bidder_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleAuctionBid<'info> {
+    /// Delivers the bid's tokens at the uniform clearing price and refunds
+    /// whatever was paid above it, both to the bidder's associated token
+    /// accounts. Anyone may crank a settlement. A bidder that can no longer
+    /// hold the tokens is refunded in full and its tokens go back on sale.
+    /// The first settlement after an undersubscribed auction ends clears it
+    /// at the floor price.
+    pub fn settle_auction_bid(&mut self) -> Result<()> {
+        let property = &mut self.property;
+
+        if property.sale_mode == SaleMode::DutchAuction {
+            property.end_dutch_auction(Clock::get()?.unix_timestamp)?;
+        }
+
+        let clearing_price = property.dutch_auction.clearing_price;
+
+        let auction_bid = &self.auction_bid;
+        let tokens = if self.compliance_record.check_can_receive(property).is_ok() {
+            auction_bid.tokens
+        } else {
+            property.available_tokens = property
+                .available_tokens
+                .checked_add(auction_bid.tokens)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+            0
+        };
+        let cost = tokens
+            .checked_mul(clearing_price)
+            .ok_or(crate::errors::Errors::MultiplicationError)?;
+        let refund = auction_bid
+            .usdc_paid
+            .checked_sub(cost)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        let seeds = &[
+            b"property",
+            property.creator.as_ref(),
+            &property.property_name,
+            &[property.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        if tokens > 0 {
+            let cpi_accounts = Transfer {
+                from: self.property_vault.to_account_info(),
+                to: self.bidder_property_token_account.to_account_info(),
+                authority: property.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, tokens)?;
+        }
+
+        if refund > 0 {
+            let cpi_accounts = Transfer {
+                from: self.property_usdc_account.to_account_info(),
+                to: self.bidder_usdc_account.to_account_info(),
+                authority: property.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, refund)?;
+        }
+
+        let investment_account = &mut self.investment_account;
+        if investment_account.investor == Pubkey::default() {
+            investment_account.investor = self.bidder.key();
+            investment_account.property = property.key();
+        }
+        investment_account.add_tokens(tokens, cost, property)?;
+
+        property.sale_escrow_held -= auction_bid.usdc_paid;
+        property.dutch_auction.unsettled_bids -= 1;
+
+        Ok(())
+    }
+}
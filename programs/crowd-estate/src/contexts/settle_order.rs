@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{Investor, OrderBook, Property};
+
+#[derive(Accounts)]
+pub struct SettleOrder<'info> {
+    pub payer: Signer<'info>,
+
+    /// CHECK: only identifies whose balance is settled; pins the accounts below.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty)]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = property,
+        seeds = [b"order_book", property.key().as_ref()],
+        bump = order_book.bump,
+    )]
+    pub order_book: Account<'info, OrderBook>,
+
+    #[account(mut, address = order_book.base_vault)]
+    pub base_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, address = order_book.quote_vault)]
+    pub quote_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"investment", owner.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub owner_investment_account: Account<'info, Investor>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_property_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = owner_usdc_account.owner == owner.key() @ crate::errors::Errors::InvalidUsdcAccount,
+        constraint = owner_usdc_account.mint == order_book.quote_mint @ crate::errors::Errors::InvalidUsdcAccount,
+    )]
+    pub owner_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SettleOrder<'info> {
+    /// Pays out everything the order book credited to the owner: fills of its
+    /// orders and locks released from orders taken off the book. Anyone may
+    /// settle once the property is liquidating.
+    pub fn settle_order(&mut self) -> Result<()> {
+        require!(
+            self.payer.key() == self.owner.key() || self.property.is_liquidating,
+            crate::errors::Errors::Unauthorized
+        );
+
+        let owner_investment_account = &mut self.owner_investment_account;
+        let base_owed = owner_investment_account.order_base_owed;
+        let quote_owed = owner_investment_account.order_quote_owed;
+        owner_investment_account.order_base_owed = 0;
+        owner_investment_account.order_quote_owed = 0;
+
+        let order_book = &mut self.order_book;
+        order_book.unsettled_base -= base_owed;
+        order_book.unsettled_quote -= quote_owed;
+
+        let property_key = self.property.key();
+        let seeds = &[
+            b"order_book",
+            property_key.as_ref(),
+            &[self.order_book.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        if base_owed > 0 {
+            let cpi_accounts = Transfer {
+                from: self.base_vault.to_account_info(),
+                to: self.owner_property_token_account.to_account_info(),
+                authority: self.order_book.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, base_owed)?;
+        }
+
+        if quote_owed > 0 {
+            let cpi_accounts = Transfer {
+                from: self.quote_vault.to_account_info(),
+                to: self.owner_usdc_account.to_account_info(),
+                authority: self.order_book.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, quote_owed)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{ComplianceRecord, Investor, OrderSide, Pool, Property};
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut)]
+    pub trader: Signer<'info>,
+
+    #[account(constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty)]
+    pub property: Account<'info, Property>,
+
+    pub property_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = property,
+        seeds = [b"pool", property.key().as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, address = pool.base_vault)]
+    pub base_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, address = pool.quote_vault)]
+    pub quote_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = trader,
+        space = Investor::INIT_SPACE,
+        seeds = [b"investment", trader.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub trader_investment_account: Account<'info, Investor>,
+
+    #[account(
+        init_if_needed,
+        payer = trader,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), trader.key().as_ref()],
+        bump,
+    )]
+    pub trader_compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(
+        mut,
+        associated_token::mint = property_mint,
+        associated_token::authority = trader,
+    )]
+    pub trader_property_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = trader_usdc_account.mint == pool.quote_mint @ crate::errors::Errors::InvalidUsdcAccount,
+    )]
+    pub trader_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Swap<'info> {
+    /// A `Bid` pays USDC for property tokens, an `Ask` sells property tokens for USDC.
+    pub fn swap(&mut self, side: OrderSide, amount_in: u64, min_amount_out: u64) -> Result<()> {
+        require!(
+            !self.property.is_closed,
+            crate::errors::Errors::PropertyClosed
+        );
+        require!(
+            !self.property.is_paused,
+            crate::errors::Errors::PropertyPaused
+        );
+        require!(amount_in > 0, crate::errors::Errors::InsufficientAmount);
+
+        let dividends_per_token = self.property.dividends_per_token;
+        let pool = &mut self.pool;
+        pool.sync_dividends(dividends_per_token)?;
+
+        let trader_investment_account = &mut self.trader_investment_account;
+        if trader_investment_account.investor == Pubkey::default() {
+            trader_investment_account.investor = self.trader.key();
+            trader_investment_account.property = self.property.key();
+        }
+        self.trader_compliance_record.register(
+            self.trader.key(),
+            self.property.key(),
+            self.property.default_withholding_rate_bps,
+        );
+        match side {
+            OrderSide::Ask => self
+                .trader_compliance_record
+                .check_can_send(Clock::get()?.unix_timestamp)?,
+            OrderSide::Bid => self
+                .trader_compliance_record
+                .check_can_receive(&self.property)?,
+        }
+
+        let (amount_out, from_trader, to_pool, from_pool, to_trader) = match side {
+            OrderSide::Bid => {
+                let amount_out =
+                    pool.swap_output(amount_in, pool.quote_reserve, pool.base_reserve)?;
+                require!(
+                    amount_out < pool.base_reserve,
+                    crate::errors::Errors::NotEnoughTokens
+                );
+
+                pool.quote_reserve = pool
+                    .quote_reserve
+                    .checked_add(amount_in)
+                    .ok_or(crate::errors::Errors::OverflowError)?;
+                pool.base_reserve -= amount_out;
+                trader_investment_account.add_tokens(amount_out, amount_in, &self.property)?;
+
+                (
+                    amount_out,
+                    self.trader_usdc_account.to_account_info(),
+                    self.quote_vault.to_account_info(),
+                    self.base_vault.to_account_info(),
+                    self.trader_property_token_account.to_account_info(),
+                )
+            }
+            OrderSide::Ask => {
+                require!(
+                    trader_investment_account.unlisted_tokens() >= amount_in,
+                    crate::errors::Errors::NotEnoughTokens
+                );
+
+                let amount_out =
+                    pool.swap_output(amount_in, pool.base_reserve, pool.quote_reserve)?;
+                require!(
+                    amount_out < pool.quote_reserve,
+                    crate::errors::Errors::InsufficientFunds
+                );
+
+                pool.base_reserve = pool
+                    .base_reserve
+                    .checked_add(amount_in)
+                    .ok_or(crate::errors::Errors::OverflowError)?;
+                pool.quote_reserve -= amount_out;
+                trader_investment_account.remove_tokens(amount_in, &self.property)?;
+
+                (
+                    amount_out,
+                    self.trader_property_token_account.to_account_info(),
+                    self.base_vault.to_account_info(),
+                    self.quote_vault.to_account_info(),
+                    self.trader_usdc_account.to_account_info(),
+                )
+            }
+        };
+        require!(
+            amount_out > 0 && amount_out >= min_amount_out,
+            crate::errors::Errors::SlippageExceeded
+        );
+        pool.reset_dividend_debt(dividends_per_token)?;
+
+        let cpi_accounts = Transfer {
+            from: from_trader,
+            to: to_pool,
+            authority: self.trader.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, amount_in)?;
+
+        let property_key = self.property.key();
+        let seeds = &[b"pool", property_key.as_ref(), &[pool.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: from_pool,
+            to: to_trader,
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, amount_out)?;
+
+        Ok(())
+    }
+}
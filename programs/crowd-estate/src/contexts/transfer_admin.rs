@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::Property;
+
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut, has_one = admin)]
+    pub property: Account<'info, Property>,
+}
+
+impl<'info> TransferAdmin<'info> {
+    /// Nominates `new_admin`, who takes over once they call `accept_admin`.
+    /// `None` withdraws a pending nomination.
+    pub fn transfer_admin(&mut self, new_admin: Option<Pubkey>) -> Result<()> {
+        require!(
+            new_admin != Some(Pubkey::default()),
+            crate::errors::Errors::InvalidNewAdmin
+        );
+
+        self.property.pending_admin = new_admin;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
+use crate::{ComplianceRecord, Investor, Property};
+
 #[derive(Accounts)]
 pub struct TransferTokens<'info> {
     #[account(mut)]
@@ -18,12 +20,64 @@ pub struct TransferTokens<'info> {
     #[account(mut)]
     pub property_mint: Account<'info, Mint>,
 
+    #[account(constraint = property.mint == property_mint.key() @ crate::errors::Errors::InvalidProperty)]
+    pub property: Account<'info, Property>,
+
+    #[account(
+        mut,
+        seeds = [b"investment", authority.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub from_investment_account: Account<'info, Investor>,
+
+    #[account(
+        seeds = [b"compliance", property.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub from_compliance_record: Account<'info, ComplianceRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Investor::INIT_SPACE,
+        seeds = [b"investment", to.key().as_ref(), property.key().as_ref()],
+        bump,
+    )]
+    pub to_investment_account: Account<'info, Investor>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ComplianceRecord::INIT_SPACE,
+        seeds = [b"compliance", property.key().as_ref(), to.key().as_ref()],
+        bump,
+    )]
+    pub to_compliance_record: Account<'info, ComplianceRecord>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> TransferTokens<'info> {
+    /// Moves tokens and the matching ledger position, so dividends and vote
+    /// checkpoints follow the tokens to the recipient.
     pub fn transfer_tokens(&mut self, amount: u64) -> Result<()> {
+        require!(
+            !self.property.is_paused,
+            crate::errors::Errors::PropertyPaused
+        );
+        require!(amount > 0, crate::errors::Errors::InsufficientAmount);
+        require!(
+            self.to.key() != self.authority.key(),
+            crate::errors::Errors::InvalidRecipient
+        );
+        self.from_compliance_record
+            .check_can_send(Clock::get()?.unix_timestamp)?;
+        require!(
+            self.from_investment_account.unlisted_tokens() >= amount,
+            crate::errors::Errors::NotEnoughTokens
+        );
+
         let cpi_accounts = Transfer {
             from: self.from_token_account.to_account_info(),
             to: self.to_token_account.to_account_info(),
@@ -33,6 +87,25 @@ impl<'info> TransferTokens<'info> {
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         token::transfer(cpi_ctx, amount)?;
 
+        // The cost basis moves with the tokens, so a transfer cannot reprice them.
+        let cost_usdc = self
+            .from_investment_account
+            .remove_tokens(amount, &self.property)?;
+
+        let to_investment_account = &mut self.to_investment_account;
+        if to_investment_account.investor == Pubkey::default() {
+            to_investment_account.investor = self.to.key();
+            to_investment_account.property = self.property.key();
+        }
+        self.to_compliance_record.register(
+            self.to.key(),
+            self.property.key(),
+            self.property.default_withholding_rate_bps,
+        );
+        self.to_compliance_record
+            .check_can_receive(&self.property)?;
+        to_investment_account.add_tokens(amount, cost_usdc, &self.property)?;
+
         Ok(())
     }
 }
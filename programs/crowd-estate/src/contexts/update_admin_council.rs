@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::AdminCouncil;
+
+#[derive(Accounts)]
+pub struct UpdateAdminCouncil<'info> {
+    #[account(mut, signer)]
+    pub council: Account<'info, AdminCouncil>,
+}
+
+impl<'info> UpdateAdminCouncil<'info> {
+    /// Replaces the council's members and threshold. Only the council itself
+    /// can sign for this, so it runs through an approved council action.
+    pub fn update_admin_council(&mut self, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        AdminCouncil::validate(&members, threshold)?;
+
+        let council = &mut self.council;
+        council.members = members;
+        council.threshold = threshold;
+
+        Ok(())
+    }
+}
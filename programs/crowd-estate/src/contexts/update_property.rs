@@ -1,17 +1,24 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token::Token};
 
-use crate::Property;
+use crate::{Property, RoleAssignment};
 
 #[derive(Accounts)]
 #[instruction(token_symbol: String)]
 pub struct UpdateProperty<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub operator: Signer<'info>,
 
     #[account(mut)]
     pub property: Account<'info, Property>,
 
+    #[account(
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+        has_one = operator,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{Property, Proposal, ProposalStatus, RoleAssignment};
+
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    pub guardian: Signer<'info>,
+
+    pub property: Account<'info, Property>,
+
+    #[account(
+        seeds = [b"roles", property.key().as_ref()],
+        bump = roles.bump,
+        has_one = guardian,
+    )]
+    pub roles: Account<'info, RoleAssignment>,
+
+    #[account(mut, has_one = property)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> VetoProposal<'info> {
+    /// Blocks a passed proposal before it is executed. `reason_hash` points
+    /// at the guardian's published justification.
+    pub fn veto_proposal(&mut self, reason_hash: [u8; 32]) -> Result<()> {
+        let proposal = &mut self.proposal;
+
+        if proposal.status == ProposalStatus::Active {
+            proposal.finalize(&self.property)?;
+        }
+        require!(
+            proposal.status == ProposalStatus::Succeeded,
+            crate::errors::Errors::ProposalNotApproved
+        );
+
+        proposal.status = ProposalStatus::Vetoed;
+        proposal.veto_reason_hash = reason_hash;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
 
-use crate::{Proposal, VoteRecord};
+use crate::{Delegation, Investor, Proposal, ProposalStatus, VoteChoice, VoteRecord};
 
 #[derive(Accounts)]
 pub struct VoteOnProposal<'info> {
@@ -16,6 +17,12 @@ pub struct VoteOnProposal<'info> {
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
+    #[account(
+        seeds = [b"investment", voter.key().as_ref(), proposal.property.as_ref()],
+        bump,
+    )]
+    pub investment_account: Option<Account<'info, Investor>>,
+
     #[account(mut)]
     pub voter: Signer<'info>,
 
@@ -23,27 +30,182 @@ pub struct VoteOnProposal<'info> {
 }
 
 impl<'info> VoteOnProposal<'info> {
-    pub fn vote_on_proposal(&mut self, vote: bool) -> Result<()> {
+    /// `delegated_accounts` holds `(delegation, investor, vote_record)`
+    /// triples for delegators whose power the voter casts. Delegators that
+    /// already have a vote record for the proposal, cast directly or through
+    /// another delegate, are skipped so no weight is counted twice.
+    pub fn vote_on_proposal(
+        &mut self,
+        vote: bool,
+        delegated_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            self.proposal.status == ProposalStatus::Active,
+            crate::errors::Errors::ProposalNotActive
+        );
+        require!(
+            self.vote_record.choice.is_none(),
+            crate::errors::Errors::AlreadyVoted
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= self.proposal.voting_start && now < self.proposal.voting_end,
+            crate::errors::Errors::VotingClosed
+        );
+
+        // Votes are weighted by the voter's holdings in the proposal's property
+        // as of the proposal's snapshot, so moving tokens can't recycle them.
+        let snapshot_slot = self.proposal.snapshot_slot;
+        let own_weight = self
+            .investment_account
+            .as_ref()
+            .map_or(0, |investment_account| {
+                investment_account.balance_before(snapshot_slot)
+            });
+
+        let choice = VoteChoice::from(vote);
+        let voter = self.voter.key();
+        let mut weight = own_weight;
+        for accounts in delegated_accounts.chunks(3) {
+            let [delegation_info, investor_info, vote_record_info] = accounts else {
+                return err!(crate::errors::Errors::MissingDelegationAccount);
+            };
+            weight = weight
+                .checked_add(self.cast_delegated_vote(
+                    delegation_info,
+                    investor_info,
+                    vote_record_info,
+                    voter,
+                    choice,
+                )?)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+        }
+        require!(weight > 0, crate::errors::Errors::NoVotingPower);
+
         let proposal = &mut self.proposal;
+        proposal.add_votes(choice, weight)?;
+
         let vote_record = &mut self.vote_record;
+        vote_record.proposal = proposal.key();
+        vote_record.voter = voter;
+        vote_record.choice = Some(choice);
+        vote_record.weight = own_weight;
+        vote_record.cast_by = voter;
+
+        Ok(())
+    }
 
-        require!(!proposal.is_executed, crate::errors::Errors::ProposalAlreadyExecuted);
-        require!(!vote_record.voted, crate::errors::Errors::AlreadyVoted);
+    /// Records the delegator's vote and returns the weight it adds, or zero
+    /// when the delegator already has a vote record.
+    fn cast_delegated_vote(
+        &self,
+        delegation_info: &'info AccountInfo<'info>,
+        investor_info: &'info AccountInfo<'info>,
+        vote_record_info: &'info AccountInfo<'info>,
+        voter: Pubkey,
+        choice: VoteChoice,
+    ) -> Result<u64> {
+        let proposal = &self.proposal;
 
-        if vote {
-            proposal.votes_for = proposal
-                .votes_for
-                .checked_add(1)
-                .ok_or(crate::errors::Errors::OverflowError)?;
+        let delegation = Account::<Delegation>::try_from(delegation_info)?;
+        require!(
+            delegation.delegate == voter && delegation.property == proposal.property,
+            crate::errors::Errors::InvalidDelegate
+        );
+
+        let investor = Account::<Investor>::try_from(investor_info)?;
+        require!(
+            investor.investor == delegation.delegator && investor.property == proposal.property,
+            crate::errors::Errors::InvalidDelegate
+        );
+
+        let weight = investor.balance_before(proposal.snapshot_slot);
+        let proposal_key = proposal.key();
+        let (vote_record_key, bump) = Pubkey::find_program_address(
+            &[
+                b"vote",
+                proposal_key.as_ref(),
+                delegation.delegator.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            vote_record_info.key(),
+            vote_record_key,
+            crate::errors::Errors::InvalidDelegate
+        );
+
+        if vote_record_info.owner == &crate::ID || weight == 0 {
+            return Ok(0);
+        }
+
+        let rent = Rent::get()?.minimum_balance(VoteRecord::INIT_SPACE);
+        let seeds = &[
+            b"vote",
+            proposal_key.as_ref(),
+            delegation.delegator.as_ref(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        let system_program = self.system_program.to_account_info();
+        let current_lamports = vote_record_info.lamports();
+        if current_lamports == 0 {
+            let cpi_ctx = CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: self.voter.to_account_info(),
+                    to: vote_record_info.clone(),
+                },
+                signer_seeds,
+            );
+            system_program::create_account(
+                cpi_ctx,
+                rent,
+                VoteRecord::INIT_SPACE as u64,
+                &crate::ID,
+            )?;
         } else {
-            proposal.votes_against = proposal
-                .votes_against
-                .checked_add(1)
-                .ok_or(crate::errors::Errors::OverflowError)?;
+            // Anyone can send lamports to the address ahead of time, which makes
+            // `create_account` fail, so top it up and claim it instead.
+            let shortfall = rent.saturating_sub(current_lamports);
+            if shortfall > 0 {
+                let cpi_ctx = CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: self.voter.to_account_info(),
+                        to: vote_record_info.clone(),
+                    },
+                );
+                system_program::transfer(cpi_ctx, shortfall)?;
+            }
+            let cpi_ctx = CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate {
+                    account_to_allocate: vote_record_info.clone(),
+                },
+                signer_seeds,
+            );
+            system_program::allocate(cpi_ctx, VoteRecord::INIT_SPACE as u64)?;
+            let cpi_ctx = CpiContext::new_with_signer(
+                system_program,
+                Assign {
+                    account_to_assign: vote_record_info.clone(),
+                },
+                signer_seeds,
+            );
+            system_program::assign(cpi_ctx, &crate::ID)?;
         }
 
-        vote_record.voted = true;
+        let vote_record = VoteRecord {
+            proposal: proposal_key,
+            voter: delegation.delegator,
+            choice: Some(choice),
+            weight,
+            cast_by: voter,
+        };
+        vote_record.try_serialize(&mut &mut vote_record_info.try_borrow_mut_data()?[..])?;
 
-        Ok(())
+        Ok(weight)
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
-use crate::{Investor, Property};
+use crate::{ComplianceRecord, Investor, Property};
 
 #[derive(Accounts)]
 pub struct WithdrawInvestment<'info> {
@@ -10,17 +10,25 @@ pub struct WithdrawInvestment<'info> {
 
     // #[account(mut)]
     // pub admin: Signer<'info>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = investor_usdc_account.mint == property.quote_mint @ crate::errors::Errors::InvalidUsdcAccount,
+    )]
     pub investor_usdc_account: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        close = investor,
         has_one = investor,
         has_one = property,
     )]
     pub investment_account: Account<'info, Investor>,
 
+    #[account(
+        seeds = [b"compliance", property.key().as_ref(), investor.key().as_ref()],
+        bump,
+    )]
+    pub compliance_record: Account<'info, ComplianceRecord>,
+
     #[account(
         mut,
         associated_token::mint = property_mint,
@@ -41,7 +49,14 @@ pub struct WithdrawInvestment<'info> {
     )]
     pub property_vault: Account<'info, TokenAccount>,
 
-    #[account(mut)]
+    #[account(address = property.quote_mint @ crate::errors::Errors::InvalidUsdcAccount)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = property,
+    )]
     pub property_usdc_account: Account<'info, TokenAccount>,
 
     #[account(mut)]
@@ -52,16 +67,36 @@ pub struct WithdrawInvestment<'info> {
 }
 
 impl<'info> WithdrawInvestment<'info> {
-    pub fn withdraw_investment(&mut self) -> Result<()> {
+    pub fn withdraw_investment(&mut self, amount: u64) -> Result<()> {
         let property = &mut self.property;
         let investment_account = &mut self.investment_account;
 
         require!(!property.is_closed, crate::errors::Errors::PropertyClosed);
+        require!(!property.is_paused, crate::errors::Errors::PropertyPaused);
+        require!(amount > 0, crate::errors::Errors::InsufficientAmount);
+        self.compliance_record
+            .check_can_send(Clock::get()?.unix_timestamp)?;
+        require!(
+            investment_account.unlisted_tokens() >= amount,
+            crate::errors::Errors::NotEnoughTokens
+        );
 
-        let usdc_amount = investment_account
-            .tokens_owned
-            .checked_mul(property.token_price_usdc)
-            .ok_or(crate::errors::Errors::MultiplicationError)?;
+        let cost_usdc = investment_account.remove_tokens(amount, property)?;
+
+        // Once a sale is approved, tokens are redeemed at the liquidation
+        // price. Before that they are redeemed at what was paid for them, up
+        // to the token price, so tokens bought from a cheaper tranche cannot
+        // be sold straight back at full price.
+        let usdc_amount = if property.is_liquidating {
+            amount
+                .checked_mul(property.liquidation_price_usdc)
+                .ok_or(crate::errors::Errors::MultiplicationError)?
+        } else {
+            amount
+                .checked_mul(property.token_price_usdc)
+                .ok_or(crate::errors::Errors::MultiplicationError)?
+                .min(cost_usdc)
+        };
 
         let cpi_accounts_transfer = Transfer {
             from: self.investor_property_token_account.to_account_info(),
@@ -70,16 +105,16 @@ impl<'info> WithdrawInvestment<'info> {
         };
         let cpi_ctx_transfer =
             CpiContext::new(self.token_program.to_account_info(), cpi_accounts_transfer);
-        token::transfer(cpi_ctx_transfer, investment_account.tokens_owned)?;
+        token::transfer(cpi_ctx_transfer, amount)?;
 
         property.available_tokens = property
             .available_tokens
-            .checked_add(investment_account.tokens_owned)
+            .checked_add(amount)
             .ok_or(crate::errors::Errors::OverflowError)?;
 
         let seeds = &[
             b"property",
-            property.admin.as_ref(),
+            property.creator.as_ref(),
             &property.property_name,
             &[property.bump],
         ];
@@ -97,8 +132,10 @@ impl<'info> WithdrawInvestment<'info> {
         );
         token::transfer(cpi_ctx_usdc_transfer, usdc_amount)?;
 
-        investment_account.tokens_owned = 0;
-        investment_account.close(self.investor.to_account_info())?;
+        // Keep the position open while settled dividends are still unclaimed.
+        if investment_account.tokens_owned == 0 && investment_account.dividends_pending == 0 {
+            investment_account.close(self.investor.to_account_info())?;
+        }
 
         Ok(())
     }
@@ -60,4 +60,122 @@ pub enum Errors {
     InvestorsExist,
     #[msg("Dividends exist")]
     DividendsExist,
+    #[msg("Invalid withholding rate")]
+    InvalidWithholdingRate,
+    #[msg("Invalid tax year")]
+    InvalidTaxYear,
+    #[msg("Invalid tax vault")]
+    InvalidTaxVault,
+    #[msg("Quote expired")]
+    QuoteExpired,
+    #[msg("Token price above limit")]
+    PriceAboveLimit,
+    #[msg("Invalid USDC account")]
+    InvalidUsdcAccount,
+    #[msg("Order book full")]
+    OrderBookFull,
+    #[msg("Order not found")]
+    OrderNotFound,
+    #[msg("Missing maker account")]
+    MissingMakerAccount,
+    #[msg("Invalid fee")]
+    InvalidFee,
+    #[msg("Slippage exceeded")]
+    SlippageExceeded,
+    #[msg("Invalid sale mode")]
+    InvalidSaleMode,
+    #[msg("Invalid auction parameters")]
+    InvalidAuctionParameters,
+    #[msg("Auction not active")]
+    AuctionNotActive,
+    #[msg("Auction not ended")]
+    AuctionNotEnded,
+    #[msg("Invalid price tranches")]
+    InvalidPriceTranches,
+    #[msg("Invalid commitment round")]
+    InvalidCommitmentRound,
+    #[msg("Commitment round not active")]
+    CommitmentRoundNotActive,
+    #[msg("Commitment round not ended")]
+    CommitmentRoundNotEnded,
+    #[msg("Commitment round not finalized")]
+    CommitmentRoundNotFinalized,
+    #[msg("Self-referral is not allowed")]
+    SelfReferral,
+    #[msg("Invalid referrer")]
+    InvalidReferrer,
+    #[msg("Invalid fee account")]
+    InvalidFeeAccount,
+    #[msg("No voting power")]
+    NoVotingPower,
+    #[msg("Invalid governance parameters")]
+    InvalidGovernanceParams,
+    #[msg("Voting is closed")]
+    VotingClosed,
+    #[msg("Voting has not ended")]
+    VotingNotEnded,
+    #[msg("Quorum not reached")]
+    QuorumNotReached,
+    #[msg("Proposal cannot be closed yet")]
+    ProposalNotClosable,
+    #[msg("Proposal is not active")]
+    ProposalNotActive,
+    #[msg("Invalid metadata URI")]
+    InvalidMetadataUri,
+    #[msg("Investor token limit exceeded")]
+    InvestorLimitExceeded,
+    #[msg("Property is being liquidated")]
+    PropertyLiquidating,
+    #[msg("Insufficient reserve funds")]
+    InsufficientReserve,
+    #[msg("Invalid governed instruction")]
+    InvalidGovernedInstruction,
+    #[msg("Missing governed account")]
+    MissingGovernedAccount,
+    #[msg("Timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Proposal expired")]
+    ProposalExpired,
+    #[msg("Invalid delegate")]
+    InvalidDelegate,
+    #[msg("Missing delegation account")]
+    MissingDelegationAccount,
+    #[msg("No vote to change")]
+    NoVoteToChange,
+    #[msg("Invalid recipient")]
+    InvalidRecipient,
+    #[msg("Proposer does not hold enough tokens")]
+    InsufficientProposerTokens,
+    #[msg("Invalid deposit account")]
+    InvalidDepositAccount,
+    #[msg("Invalid council members or threshold")]
+    InvalidCouncil,
+    #[msg("Signer is not a council member")]
+    NotCouncilMember,
+    #[msg("Council member already approved this action")]
+    AlreadyApproved,
+    #[msg("Not enough council approvals")]
+    ThresholdNotMet,
+    #[msg("Council action already executed")]
+    CouncilActionExecuted,
+    #[msg("Invalid role holder")]
+    InvalidRoleHolder,
+    #[msg("Wallet is frozen")]
+    WalletFrozen,
+    #[msg("Wallet has not passed KYC")]
+    KycRequired,
+    #[msg("Tokens are still locked up")]
+    TokensLocked,
+    #[msg("Property is paused")]
+    PropertyPaused,
+    #[msg("Order would trade against the owner's own resting order")]
+    SelfCrossingOrder,
+    #[msg("Property still holds funds in escrow")]
+    EscrowsOpen,
+    #[msg("Governed instructions moved funds the property holds for others")]
+    ProtectedFundsMoved,
+    #[msg("Too many proposals are open for voting")]
+    TooManyOpenProposals,
+    #[msg("Order is below the minimum order value")]
+    OrderTooSmall,
 }
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::OrderSide;
+
+#[event]
+pub struct TradeEvent {
+    pub property: Pubkey,
+    pub maker_order_id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub taker_side: OrderSide,
+    pub price: u64,
+    pub quantity: u64,
+    pub timestamp: i64,
+}
@@ -2,10 +2,12 @@ use anchor_lang::prelude::*;
 
 mod contexts;
 mod errors;
+mod events;
 mod state;
 
 pub use contexts::*;
 pub use errors::*;
+pub use events::*;
 pub use state::*;
 
 declare_id!("rwa5WajX9npiz1iHAHYQ9AwGcKAM9Ru8DkrRkfGjN9d");
@@ -28,6 +30,7 @@ pub mod crowd_estate {
             token_price_usdc,
             token_symbol,
             bump,
+            ctx.bumps.roles,
         )
     }
 
@@ -43,8 +46,90 @@ pub mod crowd_estate {
         ctx.accounts.transfer_tokens(amount)
     }
 
-    pub fn invest_in_property(ctx: Context<InvestInProperty>, usdc_amount: u64) -> Result<()> {
-        ctx.accounts.invest_in_property(usdc_amount)
+    pub fn invest_in_property(
+        ctx: Context<InvestInProperty>,
+        tokens_to_purchase: u64,
+        max_token_price_usdc: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .invest_in_property(tokens_to_purchase, max_token_price_usdc, expiry_slot)
+    }
+
+    pub fn set_price_tranches(
+        ctx: Context<SetPriceTranches>,
+        tranches: Vec<(u64, u64)>,
+    ) -> Result<()> {
+        ctx.accounts.set_price_tranches(tranches)
+    }
+
+    pub fn configure_dutch_auction(
+        ctx: Context<ConfigureDutchAuction>,
+        start_price: u64,
+        floor_price: u64,
+        start_time: i64,
+        duration: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .configure_dutch_auction(start_price, floor_price, start_time, duration)
+    }
+
+    pub fn place_auction_bid(
+        ctx: Context<PlaceAuctionBid>,
+        tokens: u64,
+        max_price_usdc: u64,
+    ) -> Result<()> {
+        ctx.accounts.place_auction_bid(tokens, max_price_usdc)
+    }
+
+    pub fn settle_auction_bid(ctx: Context<SettleAuctionBid>) -> Result<()> {
+        ctx.accounts.settle_auction_bid()
+    }
+
+    pub fn close_auction(ctx: Context<CloseAuction>) -> Result<()> {
+        ctx.accounts.close_auction()
+    }
+
+    pub fn open_commitment_round(
+        ctx: Context<OpenCommitmentRound>,
+        end_time: i64,
+        priority_list: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts
+            .open_commitment_round(end_time, priority_list, ctx.bumps.commitment_round)
+    }
+
+    pub fn commit_to_round(ctx: Context<CommitToRound>, tokens: u64) -> Result<()> {
+        ctx.accounts.commit_to_round(tokens)
+    }
+
+    pub fn finalize_commitment_round(ctx: Context<FinalizeCommitmentRound>) -> Result<()> {
+        ctx.accounts.finalize_commitment_round()
+    }
+
+    pub fn claim_commitment(ctx: Context<ClaimCommitment>) -> Result<()> {
+        ctx.accounts.claim_commitment()
+    }
+
+    pub fn set_platform_fee(
+        ctx: Context<SetPlatformFee>,
+        platform_fee_bps: u16,
+        referral_share_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_platform_fee(platform_fee_bps, referral_share_bps)
+    }
+
+    pub fn set_fee_recipients(
+        ctx: Context<SetFeeRecipients>,
+        fee_recipient: Pubkey,
+        tax_recipient: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.set_fee_recipients(fee_recipient, tax_recipient)
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        ctx.accounts.register_referrer(ctx.bumps.referrer_stats)
     }
 
     pub fn distribute_dividends(
@@ -54,37 +139,235 @@ pub mod crowd_estate {
         ctx.accounts.distribute_dividends(total_dividends)
     }
 
-    pub fn redeem_dividends(ctx: Context<RedeemDividends>) -> Result<()> {
-        ctx.accounts.redeem_dividends()
+    pub fn redeem_dividends(ctx: Context<RedeemDividends>, year: u16) -> Result<()> {
+        ctx.accounts.redeem_dividends(year)
+    }
+
+    pub fn set_withholding_rate(
+        ctx: Context<SetWithholdingRate>,
+        withholding_rate_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.set_withholding_rate(withholding_rate_bps)
+    }
+
+    pub fn set_default_withholding_rate(
+        ctx: Context<SetDefaultWithholdingRate>,
+        withholding_rate_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_default_withholding_rate(withholding_rate_bps)
+    }
+
+    pub fn set_wallet_compliance(
+        ctx: Context<SetWalletCompliance>,
+        kyc_verified: bool,
+        frozen: bool,
+        locked_until: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_wallet_compliance(kyc_verified, frozen, locked_until)
+    }
+
+    pub fn set_kyc_required(ctx: Context<SetKycRequired>, kyc_required: bool) -> Result<()> {
+        ctx.accounts.set_kyc_required(kyc_required)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        ctx.accounts.set_paused(paused)
+    }
+
+    pub fn set_role(ctx: Context<SetRole>, role: Role, holder: Pubkey) -> Result<()> {
+        ctx.accounts.set_role(role, holder)
+    }
+
+    pub fn withdraw_investment(ctx: Context<WithdrawInvestment>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_investment(amount)
+    }
+
+    pub fn create_listing(
+        ctx: Context<CreateListing>,
+        tokens: u64,
+        price_per_token_usdc: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_listing(tokens, price_per_token_usdc, ctx.bumps.listing)
+    }
+
+    pub fn fill_listing(
+        ctx: Context<FillListing>,
+        tokens: u64,
+        max_price_per_token_usdc: u64,
+    ) -> Result<()> {
+        ctx.accounts.fill_listing(tokens, max_price_per_token_usdc)
+    }
+
+    pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+        ctx.accounts.cancel_listing()
     }
 
-    pub fn withdraw_investment(ctx: Context<WithdrawInvestment>) -> Result<()> {
-        ctx.accounts.withdraw_investment()
+    pub fn initialize_order_book(ctx: Context<InitializeOrderBook>) -> Result<()> {
+        ctx.accounts.initialize_order_book(ctx.bumps.order_book)
+    }
+
+    pub fn place_order<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PlaceOrder<'info>>,
+        side: OrderSide,
+        price: u64,
+        quantity: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .place_order(side, price, quantity, ctx.remaining_accounts)
+    }
+
+    pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u64) -> Result<()> {
+        ctx.accounts.cancel_order(order_id)
+    }
+
+    pub fn settle_order(ctx: Context<SettleOrder>) -> Result<()> {
+        ctx.accounts.settle_order()
+    }
+
+    pub fn initialize_pool(ctx: Context<InitializePool>, fee_bps: u16) -> Result<()> {
+        ctx.accounts.initialize_pool(fee_bps, ctx.bumps.pool)
+    }
+
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        base_amount: u64,
+        max_quote_amount: u64,
+        min_shares: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .add_liquidity(base_amount, max_quote_amount, min_shares)
+    }
+
+    pub fn remove_liquidity(
+        ctx: Context<RemoveLiquidity>,
+        shares: u64,
+        min_base_amount: u64,
+        min_quote_amount: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .remove_liquidity(shares, min_base_amount, min_quote_amount)
+    }
+
+    pub fn swap(
+        ctx: Context<Swap>,
+        side: OrderSide,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        ctx.accounts.swap(side, amount_in, min_amount_out)
+    }
+
+    pub fn claim_pool_dividends(ctx: Context<ClaimPoolDividends>) -> Result<()> {
+        ctx.accounts.claim_pool_dividends()
     }
 
     pub fn close_property(ctx: Context<CloseProperty>) -> Result<()> {
         ctx.accounts.close_property()
     }
 
+    pub fn set_governance_params(
+        ctx: Context<SetGovernanceParams>,
+        governance: GovernanceParams,
+    ) -> Result<()> {
+        ctx.accounts.set_governance_params(governance)
+    }
+
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
         description: String,
-        proposal_type: ProposalType,
-        new_admin: String,
-        additional_tokens: u64,
+        payload: ProposalPayload,
     ) -> Result<()> {
         ctx.accounts
-            .create_proposal(description, proposal_type, new_admin, additional_tokens)
+            .create_proposal(description, payload, ctx.bumps.governance)
     }
 
-    pub fn vote_on_proposal(
-        ctx: Context<VoteOnProposal>,
+    pub fn vote_on_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VoteOnProposal<'info>>,
         vote: bool, // true for yes, false for no
     ) -> Result<()> {
-        ctx.accounts.vote_on_proposal(vote)
+        ctx.accounts.vote_on_proposal(vote, ctx.remaining_accounts)
+    }
+
+    pub fn change_vote(ctx: Context<ChangeVote>, vote: bool) -> Result<()> {
+        ctx.accounts.change_vote(vote)
+    }
+
+    pub fn retract_vote(ctx: Context<RetractVote>) -> Result<()> {
+        ctx.accounts.retract_vote()
+    }
+
+    pub fn delegate_votes(ctx: Context<DelegateVotes>, delegate: Pubkey) -> Result<()> {
+        ctx.accounts.delegate_votes(delegate, ctx.bumps.delegation)
+    }
+
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        ctx.accounts.revoke_delegation()
+    }
+
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.execute_proposal(ctx.remaining_accounts)
+    }
+
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        ctx.accounts.cancel_proposal()
+    }
+
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        ctx.accounts.finalize_proposal()
+    }
+
+    pub fn veto_proposal(ctx: Context<VetoProposal>, reason_hash: [u8; 32]) -> Result<()> {
+        ctx.accounts.veto_proposal(reason_hash)
+    }
+
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        ctx.accounts.close_proposal()
+    }
+
+    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.transfer_admin(new_admin)
     }
 
-    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
-        ctx.accounts.execute_proposal()
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        ctx.accounts.accept_admin()
+    }
+
+    pub fn create_admin_council(
+        ctx: Context<CreateAdminCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_admin_council(members, threshold, ctx.bumps.council)
+    }
+
+    pub fn propose_council_action(
+        ctx: Context<ProposeCouncilAction>,
+        action: CouncilActionKind,
+    ) -> Result<()> {
+        ctx.accounts.propose_council_action(action)
+    }
+
+    pub fn approve_council_action(ctx: Context<ApproveCouncilAction>) -> Result<()> {
+        ctx.accounts.approve_council_action()
+    }
+
+    pub fn update_admin_council(
+        ctx: Context<UpdateAdminCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts.update_admin_council(members, threshold)
+    }
+
+    pub fn execute_council_action<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteCouncilAction<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.execute_council_action(ctx.remaining_accounts)
     }
 }
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum SaleMode {
+    FixedPrice,
+    DutchAuction,
+    Commitment,
+}
+
+#[derive(Clone, Copy, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct DutchAuction {
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub start_time: i64,
+    pub duration: i64,
+    pub clearing_price: u64,
+    pub unsettled_bids: u32,
+    pub quote_mint: Pubkey,
+}
+
+#[account]
+pub struct AuctionBid {
+    pub bidder: Pubkey,
+    pub property: Pubkey,
+    pub tokens: u64,
+    pub usdc_paid: u64,
+}
+
+impl DutchAuction {
+    pub const SIZE: usize = 8  // start_price
+                          + 8  // floor_price
+                          + 8  // start_time
+                          + 8  // duration
+                          + 8  // clearing_price
+                          + 4  // unsettled_bids
+                          + 32; // quote_mint
+
+    pub fn end_time(&self) -> i64 {
+        self.start_time.saturating_add(self.duration)
+    }
+
+    /// Price falls linearly from `start_price` to `floor_price` over `duration`.
+    pub fn price_at(&self, now: i64) -> u64 {
+        if now <= self.start_time {
+            return self.start_price;
+        }
+        if now >= self.end_time() {
+            return self.floor_price;
+        }
+
+        let elapsed = (now - self.start_time) as u128;
+        let decline =
+            (self.start_price - self.floor_price) as u128 * elapsed / self.duration as u128;
+        self.start_price - decline as u64
+    }
+}
+
+impl AuctionBid {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // bidder
+                                + 32 // property
+                                + 8  // tokens
+                                + 8; // usdc_paid
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct CommitmentRound {
+    pub property: Pubkey,
+    pub token_price_usdc: u64,
+    pub quote_mint: Pubkey,
+    pub tokens_offered: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub tokens_committed: u64,
+    pub priority_tokens_committed: u64,
+    pub priority_tokens_allocated: u64,
+    pub general_tokens_allocated: u64,
+    pub tokens_claimed: u64,
+    pub commitment_count: u32,
+    pub finalized: bool,
+    pub priority_list: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Commitment {
+    pub investor: Pubkey,
+    pub property: Pubkey,
+    pub tokens: u64,
+    pub usdc_committed: u64,
+    pub is_priority: bool,
+}
+
+impl CommitmentRound {
+    pub const MAX_PRIORITY_LIST: usize = 16;
+
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // property
+                                + 8  // token_price_usdc
+                                + 32 // quote_mint
+                                + 8  // tokens_offered
+                                + 8  // start_time
+                                + 8  // end_time
+                                + 8  // tokens_committed
+                                + 8  // priority_tokens_committed
+                                + 8  // priority_tokens_allocated
+                                + 8  // general_tokens_allocated
+                                + 8  // tokens_claimed
+                                + 4  // commitment_count
+                                + 1  // finalized
+                                + 4 + 32 * Self::MAX_PRIORITY_LIST // priority_list
+                                + 1; // bump
+
+    /// Splits the offering once the round closes. Priority investors are
+    /// served first; everyone else shares what is left. Each class is
+    /// allocated pro-rata when it asks for more than its share.
+    pub fn finalize(&mut self) {
+        let general_tokens_committed = self.tokens_committed - self.priority_tokens_committed;
+
+        self.priority_tokens_allocated = self.priority_tokens_committed.min(self.tokens_offered);
+        self.general_tokens_allocated =
+            general_tokens_committed.min(self.tokens_offered - self.priority_tokens_allocated);
+        self.finalized = true;
+    }
+
+    /// Tokens set aside for the commitments once the round is finalized.
+    pub fn tokens_allocated(&self) -> u64 {
+        self.priority_tokens_allocated + self.general_tokens_allocated
+    }
+
+    /// Tokens allocated to a commitment of `tokens`, rounded down.
+    pub fn allocation(&self, tokens: u64, is_priority: bool) -> Result<u64> {
+        let (allocated, committed) = if is_priority {
+            (
+                self.priority_tokens_allocated,
+                self.priority_tokens_committed,
+            )
+        } else {
+            (
+                self.general_tokens_allocated,
+                self.tokens_committed - self.priority_tokens_committed,
+            )
+        };
+
+        if committed == 0 {
+            return Ok(0);
+        }
+
+        Ok((tokens as u128)
+            .checked_mul(allocated as u128)
+            .ok_or(crate::errors::Errors::MultiplicationError)?
+            .checked_div(committed as u128)
+            .ok_or(crate::errors::Errors::DivisionError)? as u64)
+    }
+}
+
+impl Commitment {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // investor
+                                + 32 // property
+                                + 8  // tokens
+                                + 8  // usdc_committed
+                                + 1; // is_priority
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::Property;
+
+/// A wallet's compliance status for one property. Unlike the `Investor`
+/// position it is never closed, so a rate set by the compliance role stays
+/// with the wallet through full exits and re-entries.
+#[account]
+pub struct ComplianceRecord {
+    pub wallet: Pubkey,
+    pub property: Pubkey,
+    pub withholding_rate_bps: u16,
+    pub kyc_verified: bool,
+    pub frozen: bool,
+    pub locked_until: i64,
+}
+
+impl ComplianceRecord {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // wallet
+                                + 32 // property
+                                + 2  // withholding_rate_bps
+                                + 1  // kyc_verified
+                                + 1  // frozen
+                                + 8; // locked_until
+
+    /// Fills in a freshly created record. Wallets the compliance role has not
+    /// classified yet are withheld at the property's default rate.
+    pub fn register(
+        &mut self,
+        wallet: Pubkey,
+        property: Pubkey,
+        default_withholding_rate_bps: u16,
+    ) {
+        if self.wallet == Pubkey::default() {
+            self.wallet = wallet;
+            self.property = property;
+            self.withholding_rate_bps = default_withholding_rate_bps;
+        }
+    }
+
+    /// Checks the wallet may take on tokens of `property`.
+    pub fn check_can_receive(&self, property: &Property) -> Result<()> {
+        require!(!self.frozen, crate::errors::Errors::WalletFrozen);
+        require!(
+            self.kyc_verified || !property.kyc_required,
+            crate::errors::Errors::KycRequired
+        );
+
+        Ok(())
+    }
+
+    /// Checks the wallet may move tokens out of its position.
+    pub fn check_can_send(&self, now: i64) -> Result<()> {
+        require!(!self.frozen, crate::errors::Errors::WalletFrozen);
+        require!(
+            now >= self.locked_until,
+            crate::errors::Errors::TokensLocked
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use anchor_lang::InstructionData;
+
+use crate::{CommitmentRound, GovernanceParams, Property, Role};
+
+#[account]
+pub struct AdminCouncil {
+    pub property: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub action_count: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct CouncilAction {
+    pub council: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: CouncilActionKind,
+    pub approvals: Vec<Pubkey>,
+    pub executed: bool,
+}
+
+/// An admin instruction the council runs once enough members approve it.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum CouncilActionKind {
+    DistributeDividends {
+        total_dividends: u64,
+    },
+    MintAdditionalTokens {
+        amount: u64,
+    },
+    CloseProperty,
+    UpdateProperty {
+        token_symbol: String,
+    },
+    TransferAdmin {
+        new_admin: Option<Pubkey>,
+    },
+    SetRole {
+        role: Role,
+        holder: Pubkey,
+    },
+    SetPlatformFee {
+        platform_fee_bps: u16,
+        referral_share_bps: u16,
+    },
+    SetPriceTranches {
+        tranches: Vec<(u64, u64)>,
+    },
+    ConfigureDutchAuction {
+        start_price: u64,
+        floor_price: u64,
+        start_time: i64,
+        duration: i64,
+    },
+    OpenCommitmentRound {
+        end_time: i64,
+        priority_list: Vec<Pubkey>,
+    },
+    SetGovernanceParams {
+        governance: GovernanceParams,
+    },
+    InitializeOrderBook,
+    InitializePool {
+        fee_bps: u16,
+    },
+    UpdateCouncil {
+        members: Vec<Pubkey>,
+        threshold: u8,
+    },
+    SetFeeRecipients {
+        fee_recipient: Pubkey,
+        tax_recipient: Pubkey,
+    },
+}
+
+impl AdminCouncil {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // property
+                                + 4 + 32 * Self::MAX_MEMBERS // members
+                                + 1  // threshold
+                                + 8  // action_count
+                                + 1; // bump
+
+    pub const MAX_MEMBERS: usize = 10;
+
+    pub fn validate(members: &[Pubkey], threshold: u8) -> Result<()> {
+        let distinct = members
+            .iter()
+            .enumerate()
+            .all(|(index, member)| !members[..index].contains(member));
+        require!(
+            !members.is_empty()
+                && members.len() <= Self::MAX_MEMBERS
+                && distinct
+                && threshold > 0
+                && threshold as usize <= members.len(),
+            crate::errors::Errors::InvalidCouncil
+        );
+
+        Ok(())
+    }
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+
+    /// Approvals that still count: those of wallets that are members now.
+    pub fn approvals_from_members(&self, approvals: &[Pubkey]) -> usize {
+        approvals
+            .iter()
+            .filter(|approval| self.is_member(approval))
+            .count()
+    }
+}
+
+impl CouncilAction {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // council
+                                + 8  // index
+                                + 32 // proposer
+                                + CouncilActionKind::SIZE // action
+                                + 4 + 32 * AdminCouncil::MAX_MEMBERS // approvals
+                                + 1; // executed
+}
+
+impl CouncilActionKind {
+    pub const MAX_TOKEN_SYMBOL_LEN: usize = 8;
+
+    /// Sized for `OpenCommitmentRound` with a full priority list, the
+    /// largest variant.
+    pub const SIZE: usize = 1 + 8 + 4 + 32 * CommitmentRound::MAX_PRIORITY_LIST;
+
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::UpdateProperty { token_symbol } => require!(
+                !token_symbol.is_empty() && token_symbol.len() <= Self::MAX_TOKEN_SYMBOL_LEN,
+                crate::errors::Errors::InvalidTokenSymbol
+            ),
+            Self::SetPriceTranches { tranches } => require!(
+                tranches.len() <= Property::MAX_PRICE_TRANCHES,
+                crate::errors::Errors::InvalidPriceTranches
+            ),
+            Self::OpenCommitmentRound { priority_list, .. } => require!(
+                priority_list.len() <= CommitmentRound::MAX_PRIORITY_LIST,
+                crate::errors::Errors::InvalidCommitmentRound
+            ),
+            Self::SetGovernanceParams { governance } => governance.validate()?,
+            Self::UpdateCouncil { members, threshold } => {
+                AdminCouncil::validate(members, *threshold)?
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Data of the admin instruction this action runs.
+    pub fn instruction_data(&self) -> Vec<u8> {
+        match self.clone() {
+            Self::DistributeDividends { total_dividends } => {
+                crate::instruction::DistributeDividends { total_dividends }.data()
+            }
+            Self::MintAdditionalTokens { amount } => {
+                crate::instruction::MintAdditionalTokens { amount }.data()
+            }
+            Self::CloseProperty => crate::instruction::CloseProperty {}.data(),
+            Self::UpdateProperty { token_symbol } => {
+                crate::instruction::UpdateProperty { token_symbol }.data()
+            }
+            Self::TransferAdmin { new_admin } => {
+                crate::instruction::TransferAdmin { new_admin }.data()
+            }
+            Self::SetRole { role, holder } => crate::instruction::SetRole { role, holder }.data(),
+            Self::SetPlatformFee {
+                platform_fee_bps,
+                referral_share_bps,
+            } => crate::instruction::SetPlatformFee {
+                platform_fee_bps,
+                referral_share_bps,
+            }
+            .data(),
+            Self::SetPriceTranches { tranches } => {
+                crate::instruction::SetPriceTranches { tranches }.data()
+            }
+            Self::ConfigureDutchAuction {
+                start_price,
+                floor_price,
+                start_time,
+                duration,
+            } => crate::instruction::ConfigureDutchAuction {
+                start_price,
+                floor_price,
+                start_time,
+                duration,
+            }
+            .data(),
+            Self::OpenCommitmentRound {
+                end_time,
+                priority_list,
+            } => crate::instruction::OpenCommitmentRound {
+                end_time,
+                priority_list,
+            }
+            .data(),
+            Self::SetGovernanceParams { governance } => {
+                crate::instruction::SetGovernanceParams { governance }.data()
+            }
+            Self::InitializeOrderBook => crate::instruction::InitializeOrderBook {}.data(),
+            Self::InitializePool { fee_bps } => {
+                crate::instruction::InitializePool { fee_bps }.data()
+            }
+            Self::UpdateCouncil { members, threshold } => {
+                crate::instruction::UpdateAdminCouncil { members, threshold }.data()
+            }
+            Self::SetFeeRecipients {
+                fee_recipient,
+                tax_recipient,
+            } => crate::instruction::SetFeeRecipients {
+                fee_recipient,
+                tax_recipient,
+            }
+            .data(),
+        }
+    }
+}
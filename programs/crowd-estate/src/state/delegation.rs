@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Delegation {
+    pub delegator: Pubkey,
+    pub property: Pubkey,
+    pub delegate: Pubkey,
+    pub bump: u8,
+}
+
+impl Delegation {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // delegator
+                                + 32 // property
+                                + 32 // delegate
+                                + 1; // bump
+}
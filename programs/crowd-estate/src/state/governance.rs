@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::ProposalType;
+
+#[account]
+pub struct Governance {
+    pub property: Pubkey,
+    pub proposal_count: u64,
+    pub bump: u8,
+}
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct GovernanceParams {
+    pub voting_period: i64,
+    pub quorum_bps: u16,
+    pub approval_threshold_bps: [u16; 8],
+    pub execution_delay: i64,
+    pub grace_period: i64,
+    pub min_proposer_tokens: u64,
+    pub proposal_deposit: u64,
+    pub deposit_asset: DepositAsset,
+}
+
+/// Snapshot slot of a proposal and when its voting ends.
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct SnapshotWindow {
+    pub slot: u64,
+    pub voting_end: i64,
+}
+
+/// What a proposer's deposit is paid in.
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum DepositAsset {
+    Usdc,
+    PropertyToken,
+}
+
+impl Governance {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // property
+                                + 8  // proposal_count
+                                + 1; // bump
+}
+
+impl SnapshotWindow {
+    pub const SIZE: usize = 8  // slot
+                          + 8; // voting_end
+}
+
+impl GovernanceParams {
+    pub const SIZE: usize = 8      // voting_period
+                          + 2      // quorum_bps
+                          + 2 * 8  // approval_threshold_bps
+                          + 8      // execution_delay
+                          + 8      // grace_period
+                          + 8      // min_proposer_tokens
+                          + 8      // proposal_deposit
+                          + 1; // deposit_asset
+
+    pub const DEFAULT_VOTING_PERIOD: i64 = 3 * 24 * 60 * 60;
+    pub const DEFAULT_QUORUM_BPS: u16 = 1_000;
+    pub const DEFAULT_EXECUTION_DELAY: i64 = 2 * 24 * 60 * 60;
+    pub const DEFAULT_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60;
+    pub const SIMPLE_MAJORITY_BPS: u16 = 5_000;
+    pub const SUPERMAJORITY_BPS: u16 = 6_667;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.voting_period > 0
+                && self.execution_delay >= 0
+                && self.grace_period > 0
+                && self.quorum_bps <= 10_000
+                && self
+                    .approval_threshold_bps
+                    .iter()
+                    .all(|threshold| (Self::SIMPLE_MAJORITY_BPS..=10_000).contains(threshold)),
+            crate::errors::Errors::InvalidGovernanceParams
+        );
+
+        Ok(())
+    }
+
+    /// Threshold for a proposal type, as a share of the votes cast.
+    pub fn approval_threshold(&self, proposal_type: u8) -> Result<u16> {
+        self.approval_threshold_bps
+            .get(proposal_type as usize)
+            .copied()
+            .ok_or(crate::errors::Errors::InvalidProposalType.into())
+    }
+}
+
+impl Default for GovernanceParams {
+    /// A simple majority for everything but `ChangeAdmin`, `ApproveSale` and
+    /// `Custom`, which need two thirds.
+    fn default() -> Self {
+        let mut approval_threshold_bps = [Self::SIMPLE_MAJORITY_BPS; 8];
+        approval_threshold_bps[ProposalType::ChangeAdmin as usize] = Self::SUPERMAJORITY_BPS;
+        approval_threshold_bps[ProposalType::ApproveSale as usize] = Self::SUPERMAJORITY_BPS;
+        approval_threshold_bps[ProposalType::Custom as usize] = Self::SUPERMAJORITY_BPS;
+
+        Self {
+            voting_period: Self::DEFAULT_VOTING_PERIOD,
+            quorum_bps: Self::DEFAULT_QUORUM_BPS,
+            approval_threshold_bps,
+            execution_delay: Self::DEFAULT_EXECUTION_DELAY,
+            grace_period: Self::DEFAULT_GRACE_PERIOD,
+            min_proposer_tokens: 0,
+            proposal_deposit: 0,
+            deposit_asset: DepositAsset::Usdc,
+        }
+    }
+}
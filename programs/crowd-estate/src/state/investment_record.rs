@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct InvestmentRecord {
+    pub investor: Pubkey,
+    pub property: Pubkey,
+    pub index: u64,
+    pub tokens: u64,
+    pub token_price_usdc: u64,
+    pub usdc_amount: u64,
+    pub timestamp: i64,
+}
+
+impl InvestmentRecord {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // investor
+                                + 32 // property
+                                + 8  // index
+                                + 8  // tokens
+                                + 8  // token_price_usdc
+                                + 8  // usdc_amount
+                                + 8; // timestamp
+}
@@ -1,11 +1,30 @@
 use anchor_lang::prelude::*;
 
+use crate::{mul_div, Property};
+
 #[account]
 pub struct Investor {
     pub investor: Pubkey,
     pub property: Pubkey,
     pub tokens_owned: u64,
     pub dividends_claimed: u64,
+    pub dividend_debt: u64,
+    pub dividends_pending: u64,
+    pub tokens_listed: u64,
+    /// Order book fills and released locks waiting for `settle_order`.
+    pub order_base_owed: u64,
+    pub order_quote_owed: u64,
+    pub tranche_tokens: [u64; Property::MAX_PRICE_TRANCHES],
+    /// USDC paid for the tokens currently owned.
+    pub cost_basis_usdc: u64,
+    pub referrer: Option<Pubkey>,
+    pub checkpoints: Vec<BalanceCheckpoint>,
+}
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct BalanceCheckpoint {
+    pub slot: u64,
+    pub tokens_owned: u64,
 }
 
 impl Investor {
@@ -13,5 +32,151 @@ impl Investor {
                                 + 32 // investor
                                 + 32 // property
                                 + 8  // tokens_owned
-                                + 8; // dividends_claimed
+                                + 8  // dividends_claimed
+                                + 8  // dividend_debt
+                                + 8  // dividends_pending
+                                + 8  // tokens_listed
+                                + 8  // order_base_owed
+                                + 8  // order_quote_owed
+                                + 8 * Property::MAX_PRICE_TRANCHES // tranche_tokens
+                                + 8  // cost_basis_usdc
+                                + 1 + 32 // referrer
+                                + 4 + 16 * Self::MAX_CHECKPOINTS; // checkpoints
+
+    pub const MAX_CHECKPOINTS: usize = 8;
+
+    fn accrued_dividends(&self, dividends_per_token: u128) -> Result<u64> {
+        let accrued = (self.tokens_owned as u128)
+            .checked_mul(dividends_per_token)
+            .ok_or(crate::errors::Errors::MultiplicationError)?
+            .checked_div(Property::DIVIDEND_PRECISION)
+            .ok_or(crate::errors::Errors::DivisionError)?;
+
+        u64::try_from(accrued).map_err(|_| crate::errors::Errors::OverflowError.into())
+    }
+
+    /// Moves everything accrued on the current balance into `dividends_pending`.
+    /// Must run before `tokens_owned` changes.
+    pub fn settle_dividends(&mut self, dividends_per_token: u128) -> Result<()> {
+        let accrued = self.accrued_dividends(dividends_per_token)?;
+        let owed = accrued
+            .checked_sub(self.dividend_debt)
+            .ok_or(crate::errors::Errors::InvalidDividendsClaim)?;
+
+        self.dividends_pending = self
+            .dividends_pending
+            .checked_add(owed)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        self.dividend_debt = accrued;
+
+        Ok(())
+    }
+
+    /// Settles dividends and grows the position by `amount` tokens that cost
+    /// `cost_usdc` in total.
+    pub fn add_tokens(&mut self, amount: u64, cost_usdc: u64, property: &Property) -> Result<()> {
+        self.settle_dividends(property.dividends_per_token)?;
+        self.tokens_owned = self
+            .tokens_owned
+            .checked_add(amount)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        self.cost_basis_usdc = self
+            .cost_basis_usdc
+            .checked_add(cost_usdc)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        self.record_checkpoint(property)?;
+
+        self.reset_dividend_debt(property.dividends_per_token)
+    }
+
+    /// Settles dividends and shrinks the position by `amount` tokens. Returns
+    /// the share of the cost basis that leaves with them.
+    pub fn remove_tokens(&mut self, amount: u64, property: &Property) -> Result<u64> {
+        require!(
+            self.tokens_owned >= amount,
+            crate::errors::Errors::NotEnoughTokens
+        );
+
+        let cost_usdc = if amount == self.tokens_owned {
+            self.cost_basis_usdc
+        } else {
+            mul_div(
+                self.cost_basis_usdc as u128,
+                amount as u128,
+                self.tokens_owned as u128,
+            )?
+        };
+        self.cost_basis_usdc -= cost_usdc;
+
+        self.settle_dividends(property.dividends_per_token)?;
+        self.tokens_owned -= amount;
+        self.record_checkpoint(property)?;
+        self.reset_dividend_debt(property.dividends_per_token)?;
+
+        Ok(cost_usdc)
+    }
+
+    /// Records the balance as of the current slot. Once `MAX_CHECKPOINTS`
+    /// are kept, the oldest checkpoint no open proposal's snapshot reads from
+    /// is dropped. `Property::MAX_OPEN_SNAPSHOTS` stays below
+    /// `MAX_CHECKPOINTS`, so there always is one.
+    fn record_checkpoint(&mut self, property: &Property) -> Result<()> {
+        let clock = Clock::get()?;
+        let slot = clock.slot;
+
+        match self.checkpoints.last_mut() {
+            Some(checkpoint) if checkpoint.slot == slot => {
+                checkpoint.tokens_owned = self.tokens_owned;
+            }
+            _ => {
+                if self.checkpoints.len() == Self::MAX_CHECKPOINTS {
+                    let unread = (0..self.checkpoints.len())
+                        .find(|&index| {
+                            let from_slot = self.checkpoints[index].slot;
+                            let to_slot = self
+                                .checkpoints
+                                .get(index + 1)
+                                .map_or(slot, |checkpoint| checkpoint.slot);
+                            !property.reads_snapshot_between(
+                                from_slot,
+                                to_slot,
+                                clock.unix_timestamp,
+                            )
+                        })
+                        .ok_or(crate::errors::Errors::TooManyOpenProposals)?;
+                    self.checkpoints.remove(unread);
+                }
+                self.checkpoints.push(BalanceCheckpoint {
+                    slot,
+                    tokens_owned: self.tokens_owned,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Balance before `slot` started, which can no longer change. Checkpoints
+    /// are only dropped once no open proposal snapshots inside their range,
+    /// so this is exact for every proposal still being voted on.
+    pub fn balance_before(&self, slot: u64) -> u64 {
+        self.checkpoints
+            .iter()
+            .rev()
+            .find(|checkpoint| checkpoint.slot < slot)
+            .map_or(0, |checkpoint| checkpoint.tokens_owned)
+    }
+
+    /// Tokens not escrowed in an open listing.
+    pub fn unlisted_tokens(&self) -> u64 {
+        self.tokens_owned.saturating_sub(self.tokens_listed)
+    }
+
+    /// Re-bases the dividend debt after `tokens_owned` changed, so the new
+    /// balance only earns dividends distributed from now on.
+    pub fn reset_dividend_debt(&mut self, dividends_per_token: u128) -> Result<()> {
+        self.dividend_debt = self.accrued_dividends(dividends_per_token)?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Listing {
+    pub seller: Pubkey,
+    pub property: Pubkey,
+    pub quote_mint: Pubkey,
+    pub price_per_token_usdc: u64,
+    pub tokens_remaining: u64,
+    pub bump: u8,
+}
+
+impl Listing {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // seller
+                                + 32 // property
+                                + 32 // quote_mint
+                                + 8  // price_per_token_usdc
+                                + 8  // tokens_remaining
+                                + 1; // bump
+}
@@ -6,3 +6,29 @@ pub mod proposal;
 pub use proposal::*;
 pub mod vote;
 pub use vote::*;
+pub mod tax_record;
+pub use tax_record::*;
+pub mod investment_record;
+pub use investment_record::*;
+pub mod listing;
+pub use listing::*;
+pub mod order_book;
+pub use order_book::*;
+pub mod pool;
+pub use pool::*;
+pub mod auction;
+pub use auction::*;
+pub mod commitment;
+pub use commitment::*;
+pub mod referrer;
+pub use referrer::*;
+pub mod governance;
+pub use governance::*;
+pub mod delegation;
+pub use delegation::*;
+pub mod council;
+pub use council::*;
+pub mod roles;
+pub use roles::*;
+pub mod compliance;
+pub use compliance::*;
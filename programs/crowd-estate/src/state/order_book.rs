@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+
+use crate::Investor;
+
+#[account]
+pub struct OrderBook {
+    pub property: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub next_order_id: u64,
+    pub bump: u8,
+    /// Fills and released locks credited to investors but not yet settled.
+    pub unsettled_base: u64,
+    pub unsettled_quote: u64,
+    pub bids: Vec<Order>,
+    pub asks: Vec<Order>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct Order {
+    pub order_id: u64,
+    pub owner: Pubkey,
+    pub price: u64,
+    pub quantity: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum OrderSide {
+    Bid,
+    Ask,
+}
+
+impl OrderSide {
+    pub fn opposite(&self) -> OrderSide {
+        match self {
+            OrderSide::Bid => OrderSide::Ask,
+            OrderSide::Ask => OrderSide::Bid,
+        }
+    }
+}
+
+impl Order {
+    pub const SIZE: usize = 8  // order_id
+                          + 32 // owner
+                          + 8  // price
+                          + 8  // quantity
+                          + 8; // timestamp
+}
+
+impl OrderBook {
+    pub const MAX_ORDERS_PER_SIDE: usize = 32;
+    /// Smallest value an order may lock, in quote units (10 USDC), so the
+    /// book's slots cannot be filled with dust.
+    pub const MIN_ORDER_VALUE: u64 = 10_000_000;
+
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // property
+                                + 32 // base_mint
+                                + 32 // quote_mint
+                                + 32 // base_vault
+                                + 32 // quote_vault
+                                + 8  // next_order_id
+                                + 1  // bump
+                                + 8  // unsettled_base
+                                + 8  // unsettled_quote
+                                + 4 + Order::SIZE * Self::MAX_ORDERS_PER_SIDE  // bids
+                                + 4 + Order::SIZE * Self::MAX_ORDERS_PER_SIDE; // asks
+
+    pub fn orders(&self, side: OrderSide) -> &Vec<Order> {
+        match side {
+            OrderSide::Bid => &self.bids,
+            OrderSide::Ask => &self.asks,
+        }
+    }
+
+    pub fn orders_mut(&mut self, side: OrderSide) -> &mut Vec<Order> {
+        match side {
+            OrderSide::Bid => &mut self.bids,
+            OrderSide::Ask => &mut self.asks,
+        }
+    }
+
+    /// Whether a resting order trades against a `taker_side` order at `limit_price`.
+    fn crosses(order: &Order, taker_side: OrderSide, limit_price: u64) -> bool {
+        match taker_side {
+            OrderSide::Bid => order.price <= limit_price,
+            OrderSide::Ask => order.price >= limit_price,
+        }
+    }
+
+    /// Whether a `taker_side` order at `limit_price` would trade against one
+    /// of the taker's own resting orders.
+    pub fn crosses_own_order(
+        &self,
+        taker_side: OrderSide,
+        limit_price: u64,
+        taker: &Pubkey,
+    ) -> bool {
+        self.orders(taker_side.opposite()).iter().any(|order| {
+            order.quantity > 0
+                && order.owner == *taker
+                && Self::crosses(order, taker_side, limit_price)
+        })
+    }
+
+    /// Index of the resting order a `taker_side` order at `limit_price` should
+    /// match next: best price first, then oldest. Orders in `passed_over` are
+    /// left alone.
+    pub fn best_match(
+        &self,
+        taker_side: OrderSide,
+        limit_price: u64,
+        passed_over: &[u64],
+    ) -> Option<usize> {
+        self.orders(taker_side.opposite())
+            .iter()
+            .enumerate()
+            .filter(|(_, order)| {
+                order.quantity > 0
+                    && !passed_over.contains(&order.order_id)
+                    && Self::crosses(order, taker_side, limit_price)
+            })
+            .min_by(|(_, a), (_, b)| {
+                let by_price = match taker_side {
+                    OrderSide::Bid => a.price.cmp(&b.price),
+                    OrderSide::Ask => b.price.cmp(&a.price),
+                };
+                by_price.then(a.order_id.cmp(&b.order_id))
+            })
+            .map(|(index, _)| index)
+    }
+
+    /// Credits `base` and `quote` to `investor`'s settlement balance.
+    pub fn credit(&mut self, investor: &mut Investor, base: u64, quote: u64) -> Result<()> {
+        investor.order_base_owed = investor
+            .order_base_owed
+            .checked_add(base)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        investor.order_quote_owed = investor
+            .order_quote_owed
+            .checked_add(quote)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        self.unsettled_base = self
+            .unsettled_base
+            .checked_add(base)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        self.unsettled_quote = self
+            .unsettled_quote
+            .checked_add(quote)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        Ok(())
+    }
+
+    pub fn find_order(&self, order_id: u64) -> Option<(OrderSide, usize)> {
+        [OrderSide::Bid, OrderSide::Ask].into_iter().find_map(|side| {
+            self.orders(side)
+                .iter()
+                .position(|order| order.order_id == order_id)
+                .map(|index| (side, index))
+        })
+    }
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+
+use crate::Property;
+
+#[account]
+pub struct Pool {
+    pub property: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub base_reserve: u64,
+    pub quote_reserve: u64,
+    pub total_shares: u64,
+    pub fee_bps: u16,
+    pub dividend_debt: u64,
+    pub dividends_per_share: u128,
+    pub bump: u8,
+}
+
+#[account]
+pub struct LpPosition {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub shares: u64,
+    pub dividend_debt: u64,
+    pub dividends_pending: u64,
+}
+
+impl Pool {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // property
+                                + 32 // base_mint
+                                + 32 // quote_mint
+                                + 32 // base_vault
+                                + 32 // quote_vault
+                                + 8  // base_reserve
+                                + 8  // quote_reserve
+                                + 8  // total_shares
+                                + 2  // fee_bps
+                                + 8  // dividend_debt
+                                + 16 // dividends_per_share
+                                + 1; // bump
+
+    /// Credits dividends earned by the pooled tokens to the LP shares.
+    /// Must run before `base_reserve` or `total_shares` changes.
+    pub fn sync_dividends(&mut self, dividends_per_token: u128) -> Result<()> {
+        let accrued = mul_div(
+            self.base_reserve as u128,
+            dividends_per_token,
+            Property::DIVIDEND_PRECISION,
+        )?;
+        let earned = accrued
+            .checked_sub(self.dividend_debt)
+            .ok_or(crate::errors::Errors::InvalidDividendsClaim)?;
+
+        if earned > 0 && self.total_shares > 0 {
+            let per_share = (earned as u128)
+                .checked_mul(Property::DIVIDEND_PRECISION)
+                .ok_or(crate::errors::Errors::MultiplicationError)?
+                / self.total_shares as u128;
+            self.dividends_per_share = self
+                .dividends_per_share
+                .checked_add(per_share)
+                .ok_or(crate::errors::Errors::OverflowError)?;
+        }
+        self.dividend_debt = accrued;
+
+        Ok(())
+    }
+
+    /// Re-bases the pool's dividend debt after `base_reserve` changed.
+    pub fn reset_dividend_debt(&mut self, dividends_per_token: u128) -> Result<()> {
+        self.dividend_debt = mul_div(
+            self.base_reserve as u128,
+            dividends_per_token,
+            Property::DIVIDEND_PRECISION,
+        )?;
+
+        Ok(())
+    }
+
+    /// Output of a constant-product swap after the pool fee.
+    pub fn swap_output(&self, amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+        let amount_in_after_fee =
+            mul_div(amount_in as u128, 10_000 - self.fee_bps as u128, 10_000)?;
+
+        mul_div(
+            amount_in_after_fee as u128,
+            reserve_out as u128,
+            (reserve_in as u128)
+                .checked_add(amount_in_after_fee as u128)
+                .ok_or(crate::errors::Errors::OverflowError)?,
+        )
+    }
+}
+
+impl LpPosition {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // owner
+                                + 32 // pool
+                                + 8  // shares
+                                + 8  // dividend_debt
+                                + 8; // dividends_pending
+
+    pub fn settle_dividends(&mut self, dividends_per_share: u128) -> Result<()> {
+        let accrued = mul_div(
+            self.shares as u128,
+            dividends_per_share,
+            Property::DIVIDEND_PRECISION,
+        )?;
+        let owed = accrued
+            .checked_sub(self.dividend_debt)
+            .ok_or(crate::errors::Errors::InvalidDividendsClaim)?;
+
+        self.dividends_pending = self
+            .dividends_pending
+            .checked_add(owed)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+        self.dividend_debt = accrued;
+
+        Ok(())
+    }
+
+    pub fn reset_dividend_debt(&mut self, dividends_per_share: u128) -> Result<()> {
+        self.dividend_debt = mul_div(
+            self.shares as u128,
+            dividends_per_share,
+            Property::DIVIDEND_PRECISION,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// `a * b / c`, rounded down, checked to fit in a `u64`.
+pub fn mul_div(a: u128, b: u128, c: u128) -> Result<u64> {
+    let result = a
+        .checked_mul(b)
+        .ok_or(crate::errors::Errors::MultiplicationError)?
+        .checked_div(c)
+        .ok_or(crate::errors::Errors::DivisionError)?;
+
+    u64::try_from(result).map_err(|_| crate::errors::Errors::OverflowError.into())
+}
+
+/// Integer square root, used to size the first liquidity deposit.
+pub fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
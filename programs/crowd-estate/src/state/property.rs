@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{DutchAuction, GovernanceParams, Investor, SaleMode, SnapshotWindow};
+
 #[account]
 pub struct Property {
     pub property_name: Vec<u8>,
@@ -8,10 +10,46 @@ pub struct Property {
     pub token_price_usdc: u64,
     pub token_symbol: Vec<u8>,
     pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    pub creator: Pubkey,
     pub mint: Pubkey,
     pub bump: u8,
     pub dividends_total: u64,
     pub is_closed: bool,
+    pub dividends_per_token: u128,
+    pub investment_count: u64,
+    pub sale_mode: SaleMode,
+    pub dutch_auction: DutchAuction,
+    pub price_tranches: Vec<PriceTranche>,
+    pub platform_fee_bps: u16,
+    pub referral_share_bps: u16,
+    pub governance: GovernanceParams,
+    pub metadata_uri: String,
+    pub max_tokens_per_investor: u64,
+    pub is_liquidating: bool,
+    pub liquidation_price_usdc: u64,
+    pub proposal_deposits_held: u64,
+    pub default_withholding_rate_bps: u16,
+    pub kyc_required: bool,
+    pub is_paused: bool,
+    pub open_listings: u32,
+    pub open_proposal_deposits: u32,
+    pub sale_escrow_held: u64,
+    pub quote_mint: Pubkey,
+    /// Wallets whose USDC accounts take platform fees and withheld tax. Kept
+    /// apart from the admin and role keys, which may be program accounts.
+    pub fee_recipient: Pubkey,
+    pub tax_recipient: Pubkey,
+    /// Snapshots of proposals that may still be voting, so investor
+    /// checkpoints they read are kept.
+    pub open_snapshots: Vec<SnapshotWindow>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct PriceTranche {
+    pub tokens: u64,
+    pub price_usdc: u64,
+    pub tokens_sold: u64,
 }
 
 impl Property {
@@ -22,8 +60,116 @@ impl Property {
                                 + 8  // token_price_usdc
                                 + 32 // token_symbol
                                 + 32 // admin
+                                + 1 + 32 // pending_admin
+                                + 32 // creator
                                 + 32 // mint
                                 + 1  // bump
                                 + 8  // dividends_total
-                                + 1; // is_closed
+                                + 1  // is_closed
+                                + 16 // dividends_per_token
+                                + 8  // investment_count
+                                + 1  // sale_mode
+                                + DutchAuction::SIZE // dutch_auction
+                                + 4 + PriceTranche::SIZE * Self::MAX_PRICE_TRANCHES // price_tranches
+                                + 2  // platform_fee_bps
+                                + 2  // referral_share_bps
+                                + GovernanceParams::SIZE // governance
+                                + 4 + Self::MAX_METADATA_URI_LEN // metadata_uri
+                                + 8  // max_tokens_per_investor
+                                + 1  // is_liquidating
+                                + 8  // liquidation_price_usdc
+                                + 8  // proposal_deposits_held
+                                + 2  // default_withholding_rate_bps
+                                + 1  // kyc_required
+                                + 1  // is_paused
+                                + 4  // open_listings
+                                + 4  // open_proposal_deposits
+                                + 8  // sale_escrow_held
+                                + 32 // quote_mint
+                                + 32 // fee_recipient
+                                + 32 // tax_recipient
+                                + 4 + SnapshotWindow::SIZE * Self::MAX_OPEN_SNAPSHOTS; // open_snapshots
+
+    pub const MAX_PRICE_TRANCHES: usize = 4;
+    pub const MAX_METADATA_URI_LEN: usize = 200;
+    pub const MAX_OPEN_SNAPSHOTS: usize = Investor::MAX_CHECKPOINTS - 1;
+
+    /// Fixed-point scale of `dividends_per_token`.
+    pub const DIVIDEND_PRECISION: u128 = 1_000_000_000_000;
+
+    /// Ends a Dutch auction that sold out or ran past its end time, returning
+    /// the property to fixed-price sales at the clearing price. An auction
+    /// that did not sell out clears at its floor price.
+    pub fn end_dutch_auction(&mut self, now: i64) -> Result<()> {
+        require!(
+            self.sale_mode == SaleMode::DutchAuction,
+            crate::errors::Errors::InvalidSaleMode
+        );
+        if self.dutch_auction.clearing_price == 0 {
+            require!(
+                now >= self.dutch_auction.end_time(),
+                crate::errors::Errors::AuctionNotEnded
+            );
+            self.dutch_auction.clearing_price = self.dutch_auction.floor_price;
+        }
+
+        self.sale_mode = SaleMode::FixedPrice;
+        self.token_price_usdc = self.dutch_auction.clearing_price;
+
+        Ok(())
+    }
+
+    /// Tracks the snapshot of a new proposal until its voting ends, dropping
+    /// those that already ended.
+    pub fn open_snapshot(&mut self, window: SnapshotWindow, now: i64) -> Result<()> {
+        self.open_snapshots
+            .retain(|open_window| open_window.voting_end > now);
+        require!(
+            self.open_snapshots.len() < Self::MAX_OPEN_SNAPSHOTS,
+            crate::errors::Errors::TooManyOpenProposals
+        );
+        self.open_snapshots.push(window);
+
+        Ok(())
+    }
+
+    /// Whether a proposal still voting at `now` snapshotted a slot in
+    /// `(from_slot, to_slot]`, reading the balance recorded at `from_slot`.
+    pub fn reads_snapshot_between(&self, from_slot: u64, to_slot: u64, now: i64) -> bool {
+        self.open_snapshots.iter().any(|window| {
+            window.voting_end > now && window.slot > from_slot && window.slot <= to_slot
+        })
+    }
+
+    /// Cost of buying `tokens`, filling the price tranches in order and
+    /// pricing anything past the last tranche at `token_price_usdc`.
+    /// Also returns how many tokens come out of each tranche.
+    pub fn quote_tokens(&self, tokens: u64) -> Result<(u64, [u64; Self::MAX_PRICE_TRANCHES])> {
+        let mut remaining = tokens;
+        let mut cost: u64 = 0;
+        let mut tranche_fills = [0u64; Self::MAX_PRICE_TRANCHES];
+
+        for (index, tranche) in self.price_tranches.iter().enumerate() {
+            let fill = remaining.min(tranche.tokens - tranche.tokens_sold);
+            cost = fill
+                .checked_mul(tranche.price_usdc)
+                .and_then(|value| value.checked_add(cost))
+                .ok_or(crate::errors::Errors::MultiplicationError)?;
+            tranche_fills[index] = fill;
+            remaining -= fill;
+        }
+
+        cost = remaining
+            .checked_mul(self.token_price_usdc)
+            .and_then(|value| value.checked_add(cost))
+            .ok_or(crate::errors::Errors::MultiplicationError)?;
+
+        Ok((cost, tranche_fills))
+    }
+}
+
+impl PriceTranche {
+    pub const SIZE: usize = 8  // tokens
+                          + 8  // price_usdc
+                          + 8; // tokens_sold
 }
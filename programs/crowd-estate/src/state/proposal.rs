@@ -1,33 +1,306 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+
+use crate::{DepositAsset, Property, VoteChoice};
 
 #[account]
 pub struct Proposal {
     pub proposer: Pubkey,
     pub property: Pubkey,
+    pub index: u64,
     pub description: [u8; 256],
     pub votes_for: u64,
     pub votes_against: u64,
-    pub is_executed: bool,
+    pub status: ProposalStatus,
     pub proposal_type: u8,
-    pub new_admin: Option<Pubkey>,
-    pub additional_tokens: Option<u64>,
+    pub payload: ProposalPayload,
+    pub voting_start: i64,
+    pub snapshot_slot: u64,
+    pub voting_end: i64,
+    pub veto_reason_hash: [u8; 32],
+    pub eta: i64,
+    pub expires_at: i64,
+    pub deposit_amount: u64,
+    pub deposit_asset: DepositAsset,
+    /// Property-owned account the deposit was paid into, and its mint.
+    pub deposit_account: Pubkey,
+    pub deposit_mint: Pubkey,
+    /// Cost basis of a token deposit, handed back with a refund.
+    pub deposit_cost_basis: u64,
+    pub quorum_bps: u16,
+    pub approval_threshold_bps: u16,
+}
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum ProposalStatus {
+    Active,
+    Cancelled,
+    Defeated,
+    Succeeded,
+    Vetoed,
+    Executed,
+    Expired,
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
 pub enum ProposalType {
     MintAdditionalTokens, // 0
     ChangeAdmin,          // 1
+    ChangeTokenPrice,     // 2
+    ApproveSale,          // 3
+    UpdateFeeParameters,  // 4
+    UpdateMetadataUri,    // 5
+    ReleaseReserveFunds,  // 6
+    Custom,               // 7
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum ProposalPayload {
+    MintAdditionalTokens {
+        additional_tokens: u64,
+    },
+    ChangeAdmin {
+        new_admin: Pubkey,
+    },
+    ChangeTokenPrice {
+        token_price_usdc: u64,
+    },
+    /// Approves selling the property; holders redeem their tokens at
+    /// `liquidation_price_usdc` from then on.
+    ApproveSale {
+        liquidation_price_usdc: u64,
+    },
+    UpdateFeeParameters {
+        platform_fee_bps: u16,
+        referral_share_bps: u16,
+        max_tokens_per_investor: u64,
+    },
+    UpdateMetadataUri {
+        metadata_uri: String,
+    },
+    /// Pays `amount` of USDC not owed as dividends to the `recipient` token account.
+    ReleaseReserveFunds {
+        amount: u64,
+        recipient: Pubkey,
+    },
+    /// Instructions invoked in order with the property PDA as signer.
+    Custom {
+        instructions: Vec<GovernedInstruction>,
+    },
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct GovernedInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<GovernedAccountMeta>,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct GovernedAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl From<&GovernedInstruction> for Instruction {
+    fn from(governed: &GovernedInstruction) -> Self {
+        Instruction {
+            program_id: governed.program_id,
+            accounts: governed
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: governed.data.clone(),
+        }
+    }
+}
+
+impl ProposalPayload {
+    /// Serialized size budget of a `Custom` instruction list; also covers
+    /// `UpdateMetadataUri` with a full-length URI.
+    pub const MAX_CUSTOM_PAYLOAD_LEN: usize = 1_024;
+
+    pub const SIZE: usize = 1 + Self::MAX_CUSTOM_PAYLOAD_LEN;
+
+    pub fn proposal_type(&self) -> ProposalType {
+        match self {
+            Self::MintAdditionalTokens { .. } => ProposalType::MintAdditionalTokens,
+            Self::ChangeAdmin { .. } => ProposalType::ChangeAdmin,
+            Self::ChangeTokenPrice { .. } => ProposalType::ChangeTokenPrice,
+            Self::ApproveSale { .. } => ProposalType::ApproveSale,
+            Self::UpdateFeeParameters { .. } => ProposalType::UpdateFeeParameters,
+            Self::UpdateMetadataUri { .. } => ProposalType::UpdateMetadataUri,
+            Self::ReleaseReserveFunds { .. } => ProposalType::ReleaseReserveFunds,
+            Self::Custom { .. } => ProposalType::Custom,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::MintAdditionalTokens { additional_tokens } => require!(
+                *additional_tokens > 0,
+                crate::errors::Errors::InvalidAdditionalTokens
+            ),
+            Self::ChangeAdmin { new_admin } => require!(
+                *new_admin != Pubkey::default(),
+                crate::errors::Errors::InvalidNewAdmin
+            ),
+            Self::ChangeTokenPrice { token_price_usdc } => require!(
+                *token_price_usdc > 0,
+                crate::errors::Errors::InvalidTokenPrice
+            ),
+            Self::ApproveSale {
+                liquidation_price_usdc,
+            } => require!(
+                *liquidation_price_usdc > 0,
+                crate::errors::Errors::InvalidTokenPrice
+            ),
+            Self::UpdateFeeParameters {
+                platform_fee_bps,
+                referral_share_bps,
+                ..
+            } => require!(
+                *platform_fee_bps <= 10_000 && *referral_share_bps <= 10_000,
+                crate::errors::Errors::InvalidFee
+            ),
+            Self::UpdateMetadataUri { metadata_uri } => require!(
+                metadata_uri.len() <= Property::MAX_METADATA_URI_LEN,
+                crate::errors::Errors::InvalidMetadataUri
+            ),
+            Self::ReleaseReserveFunds { amount, .. } => {
+                require!(*amount > 0, crate::errors::Errors::InsufficientAmount)
+            }
+            // Calls back into this program could bypass its own checks, so
+            // governed instructions may only target other programs.
+            Self::Custom { instructions } => require!(
+                !instructions.is_empty()
+                    && instructions
+                        .iter()
+                        .all(|instruction| instruction.program_id != crate::ID)
+                    && self.try_to_vec()?.len() <= Self::SIZE,
+                crate::errors::Errors::InvalidGovernedInstruction
+            ),
+        }
+
+        Ok(())
+    }
 }
 
 impl Proposal {
     pub const INIT_SPACE: usize = 8   // discriminator
                                 + 32  // property
                                 + 32  // proposer
+                                + 8   // index
                                 + 256 // description
                                 + 8   // votes_for
                                 + 8   // votes_against
-                                + 1   // is_executed
+                                + 1   // status
                                 + 1   // proposal_type
-                                + 32  // new_admin
-                                + 8; // additional_tokens
+                                + ProposalPayload::SIZE // payload
+                                + 8   // voting_start
+                                + 8   // snapshot_slot
+                                + 8   // voting_end
+                                + 32  // veto_reason_hash
+                                + 8   // eta
+                                + 8   // expires_at
+                                + 8   // deposit_amount
+                                + 1   // deposit_asset
+                                + 32  // deposit_account
+                                + 32  // deposit_mint
+                                + 8   // deposit_cost_basis
+                                + 2   // quorum_bps
+                                + 2; // approval_threshold_bps
+
+    /// Checks that voting has closed with quorum met and the approval
+    /// threshold cleared, both as they stood when the proposal was created.
+    pub fn check_outcome(&self, property: &Property) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp >= self.voting_end,
+            crate::errors::Errors::VotingNotEnded
+        );
+
+        let votes_cast = (self.votes_for as u128) + (self.votes_against as u128);
+        require!(
+            self.reached_quorum(property),
+            crate::errors::Errors::QuorumNotReached
+        );
+        // A 100% threshold means unanimity, which a strict majority test can
+        // never reach.
+        let approved = if self.approval_threshold_bps == 10_000 {
+            self.votes_for > 0 && self.votes_against == 0
+        } else {
+            (self.votes_for as u128) * 10_000 > votes_cast * (self.approval_threshold_bps as u128)
+        };
+        require!(approved, crate::errors::Errors::ProposalNotApproved);
+
+        Ok(())
+    }
+
+    /// Whether the votes cast make up the proposal's quorum, whatever the
+    /// outcome.
+    pub fn reached_quorum(&self, property: &Property) -> bool {
+        let votes_cast = (self.votes_for as u128) + (self.votes_against as u128);
+        votes_cast * 10_000 >= (property.total_tokens as u128) * (self.quorum_bps as u128)
+    }
+
+    /// Moves an active proposal whose voting has closed to `Succeeded` or
+    /// `Defeated`.
+    pub fn finalize(&mut self, property: &Property) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Active,
+            crate::errors::Errors::ProposalNotActive
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= self.voting_end,
+            crate::errors::Errors::VotingNotEnded
+        );
+
+        self.status = if self.check_outcome(property).is_ok() {
+            ProposalStatus::Succeeded
+        } else {
+            ProposalStatus::Defeated
+        };
+
+        Ok(())
+    }
+
+    pub fn add_votes(&mut self, choice: VoteChoice, weight: u64) -> Result<()> {
+        let tally = match choice {
+            VoteChoice::For => &mut self.votes_for,
+            VoteChoice::Against => &mut self.votes_against,
+        };
+        *tally = tally
+            .checked_add(weight)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        Ok(())
+    }
+
+    pub fn remove_votes(&mut self, choice: VoteChoice, weight: u64) -> Result<()> {
+        let tally = match choice {
+            VoteChoice::For => &mut self.votes_for,
+            VoteChoice::Against => &mut self.votes_against,
+        };
+        *tally = tally
+            .checked_sub(weight)
+            .ok_or(crate::errors::Errors::OverflowError)?;
+
+        Ok(())
+    }
+
+    /// Whether votes can still be cast, changed or retracted.
+    pub fn is_voting_open(&self, now: i64) -> bool {
+        self.status == ProposalStatus::Active && now >= self.voting_start && now < self.voting_end
+    }
+
+    /// A succeeded proposal that was not executed within its grace window.
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.status == ProposalStatus::Succeeded && now > self.expires_at
+    }
 }
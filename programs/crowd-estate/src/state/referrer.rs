@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ReferrerStats {
+    pub referrer: Pubkey,
+    pub usdc_account: Pubkey,
+    pub referred_investors: u64,
+    pub referred_volume_usdc: u64,
+    pub fees_earned: u64,
+    pub bump: u8,
+}
+
+impl ReferrerStats {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // referrer
+                                + 32 // usdc_account
+                                + 8  // referred_investors
+                                + 8  // referred_volume_usdc
+                                + 8  // fees_earned
+                                + 1; // bump
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct RoleAssignment {
+    pub property: Pubkey,
+    pub operator: Pubkey,
+    pub treasurer: Pubkey,
+    pub compliance: Pubkey,
+    pub guardian: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum Role {
+    /// Updates the property's metadata and closes it down.
+    Operator,
+    /// Distributes dividends and mints new tokens into the vault.
+    Treasurer,
+    /// Verifies, freezes and locks up wallets, sets withholding rates and
+    /// receives withheld tax.
+    Compliance,
+    /// Pauses the property and vetoes passed proposals.
+    Guardian,
+}
+
+impl RoleAssignment {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // property
+                                + 32 // operator
+                                + 32 // treasurer
+                                + 32 // compliance
+                                + 32 // guardian
+                                + 1; // bump
+
+    pub const ROLES: [Role; 4] = [
+        Role::Operator,
+        Role::Treasurer,
+        Role::Compliance,
+        Role::Guardian,
+    ];
+
+    pub fn holder_mut(&mut self, role: Role) -> &mut Pubkey {
+        match role {
+            Role::Operator => &mut self.operator,
+            Role::Treasurer => &mut self.treasurer,
+            Role::Compliance => &mut self.compliance,
+            Role::Guardian => &mut self.guardian,
+        }
+    }
+
+    /// Moves every role `from` holds over to `to`.
+    pub fn reassign(&mut self, from: Pubkey, to: Pubkey) {
+        for role in Self::ROLES {
+            let holder = self.holder_mut(role);
+            if *holder == from {
+                *holder = to;
+            }
+        }
+    }
+}
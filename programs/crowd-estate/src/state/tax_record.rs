@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct TaxRecord {
+    pub investor: Pubkey,
+    pub property: Pubkey,
+    pub year: u16,
+    pub withholding_rate_bps: u16,
+    pub gross_dividends: u64,
+    pub tax_withheld: u64,
+    pub net_dividends: u64,
+    pub withholding_count: u32,
+}
+
+impl TaxRecord {
+    pub const INIT_SPACE: usize = 8  // discriminator
+                                + 32 // investor
+                                + 32 // property
+                                + 2  // year
+                                + 2  // withholding_rate_bps
+                                + 8  // gross_dividends
+                                + 8  // tax_withheld
+                                + 8  // net_dividends
+                                + 4; // withholding_count
+
+    /// Calendar (UTC) year of a unix timestamp, used to bucket withholdings per tax year.
+    pub fn year_from_timestamp(timestamp: i64) -> u16 {
+        let days = timestamp.div_euclid(86_400) + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let year = year_of_era + era * 400;
+
+        if month_index >= 10 {
+            (year + 1) as u16
+        } else {
+            year as u16
+        }
+    }
+}
@@ -4,12 +4,32 @@ use anchor_lang::prelude::*;
 pub struct VoteRecord {
     pub proposal: Pubkey,
     pub voter: Pubkey,
-    pub voted: bool,
+    pub choice: Option<VoteChoice>,
+    pub weight: u64,
+    pub cast_by: Pubkey,
+}
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum VoteChoice {
+    For,
+    Against,
+}
+
+impl From<bool> for VoteChoice {
+    fn from(vote: bool) -> Self {
+        if vote {
+            VoteChoice::For
+        } else {
+            VoteChoice::Against
+        }
+    }
 }
 
 impl VoteRecord {
     pub const INIT_SPACE: usize = 8  // discriminator
                                 + 32 // proposal
                                 + 32 // voter
-                                + 1; // voted
+                                + 1 + 1 // choice
+                                + 8  // weight
+                                + 32; // cast_by
 }
@@ -5,13 +5,18 @@ import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
 import { assert } from "chai";
 import {
 	Account,
+	AuthorityType,
+	createAccount,
 	createMint,
+	createSetAuthorityInstruction,
+	createTransferInstruction,
 	getAccount,
 	getOrCreateAssociatedTokenAccount,
 	mintTo,
 	TOKEN_PROGRAM_ID,
 	ASSOCIATED_TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
+import { findInvestmentRecordPda } from "./utils";
 
 describe("RWA Platform", async () => {
 	const provider = anchor.AnchorProvider.env();
@@ -212,7 +217,7 @@ describe("RWA Platform", async () => {
 		await program.methods
 			.updateProperty(tokenSymbol)
 			.accountsPartial({
-				admin: admin.publicKey,
+				operator: admin.publicKey,
 				property: propertyPda,
 				associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
 				// systemProgram: SystemProgram.programId,
@@ -240,8 +245,13 @@ describe("RWA Platform", async () => {
 		);
 
 		await program.methods
-			.investInProperty(new anchor.BN(300 * 10 ** 6))
+			.investInProperty(
+				new anchor.BN(3),
+				pricePerToken,
+				new anchor.BN((await connection.getSlot()) + 100)
+			)
 			.accountsPartial({
+				usdcMint,
 				propertyUsdcAccount: propertyUsdcVault.address,
 				propertyVault: propertyVault.address,
 				investor: investor.publicKey,
@@ -254,6 +264,10 @@ describe("RWA Platform", async () => {
 				investorPropertyTokenAccount:
 					investorPropertyTokenAccount.address,
 				investmentAccount,
+				investmentRecord: await findInvestmentRecordPda(
+					program,
+					propertyPda
+				),
 			})
 			.signers([investor /*, admin*/])
 			.rpc()
@@ -277,6 +291,96 @@ describe("RWA Platform", async () => {
 		assert.equal(investmentAccountData.dividendsClaimed.toNumber(), 0);
 	});
 
+	it("Rejects investment above the maximum token price", async () => {
+		const [investmentAccount] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				investor.publicKey.toBuffer(),
+				propertyPda.toBuffer(),
+			],
+			program.programId
+		);
+
+		try {
+			await program.methods
+				.investInProperty(
+					new anchor.BN(1),
+					pricePerToken.subn(1),
+					new anchor.BN((await connection.getSlot()) + 100)
+				)
+				.accountsPartial({
+					usdcMint,
+					propertyUsdcAccount: propertyUsdcVault.address,
+					propertyVault: propertyVault.address,
+					investor: investor.publicKey,
+					property: propertyPda,
+					investorUsdcAccount: investorUsdcAccount.address,
+					propertyMint,
+					investorPropertyTokenAccount:
+						investorPropertyTokenAccount.address,
+					investmentAccount,
+					investmentRecord: await findInvestmentRecordPda(
+						program,
+						propertyPda
+					),
+				})
+				.signers([investor])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.toString(), "PriceAboveLimit");
+		}
+	});
+
+	it("Rejects investment paid into an account other than the property's", async () => {
+		const [investmentAccount] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				investor.publicKey.toBuffer(),
+				propertyPda.toBuffer(),
+			],
+			program.programId
+		);
+		// A second USDC account the investor controls.
+		const sideAccount = await createAccount(
+			connection,
+			investor,
+			usdcMint,
+			investor.publicKey,
+			Keypair.generate()
+		);
+
+		try {
+			await program.methods
+				.investInProperty(
+					new anchor.BN(1),
+					pricePerToken,
+					new anchor.BN((await connection.getSlot()) + 100)
+				)
+				.accountsPartial({
+					usdcMint,
+					propertyUsdcAccount: sideAccount,
+					propertyVault: propertyVault.address,
+					investor: investor.publicKey,
+					property: propertyPda,
+					investorUsdcAccount: investorUsdcAccount.address,
+					propertyMint,
+					investorPropertyTokenAccount:
+						investorPropertyTokenAccount.address,
+					investmentAccount,
+					investmentRecord: await findInvestmentRecordPda(
+						program,
+						propertyPda
+					),
+				})
+				.signers([investor])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.toString(), "ConstraintAssociated");
+		}
+	});
+
 	it("Rejects investment without enough USDC", async () => {
 		const [investmentAccount] = PublicKey.findProgramAddressSync(
 			[
@@ -288,8 +392,9 @@ describe("RWA Platform", async () => {
 		);
 
 		await program.methods
-			.withdrawInvestment()
+			.withdrawInvestment(new anchor.BN(3))
 			.accountsPartial({
+				usdcMint,
 				propertyUsdcAccount: propertyUsdcVault.address,
 				propertyVault: propertyVault.address,
 				investor: investor.publicKey,
@@ -309,8 +414,13 @@ describe("RWA Platform", async () => {
 
 		try {
 			await program.methods
-				.investInProperty(new anchor.BN(300 * 10 ** 6))
+				.investInProperty(
+					new anchor.BN(3),
+					pricePerToken,
+					new anchor.BN((await connection.getSlot()) + 100)
+				)
 				.accountsPartial({
+					usdcMint,
 					propertyUsdcAccount: propertyUsdcVault.address,
 					propertyVault: propertyVault.address,
 					investor: investor.publicKey,
@@ -323,6 +433,10 @@ describe("RWA Platform", async () => {
 					investorPropertyTokenAccount:
 						investorPropertyTokenAccount.address,
 					investmentAccount: investmentAccount,
+					investmentRecord: await findInvestmentRecordPda(
+						program,
+						propertyPda
+					),
 				})
 				.signers([investor /*, admin*/])
 				.rpc();
@@ -344,8 +458,13 @@ describe("RWA Platform", async () => {
 
 		try {
 			const tx = program.methods
-				.investInProperty(new anchor.BN(50 * 10 ** 6))
+				.investInProperty(
+					new anchor.BN(0),
+					pricePerToken,
+					new anchor.BN((await connection.getSlot()) + 100)
+				)
 				.accountsPartial({
+					usdcMint,
 					investor: investor.publicKey,
 					property: propertyPda,
 					investorUsdcAccount: investorUsdcAccount.address,
@@ -356,6 +475,10 @@ describe("RWA Platform", async () => {
 					investorPropertyTokenAccount:
 						investorPropertyTokenAccount.address,
 					investmentAccount: investmentAccount,
+					investmentRecord: await findInvestmentRecordPda(
+						program,
+						propertyPda
+					),
 				})
 				.signers([investor /*, admin*/])
 				.rpc();
@@ -381,8 +504,8 @@ describe("RWA Platform", async () => {
 		await program.methods
 			.distributeDividends(new anchor.BN(1_000 * 10 ** 6))
 			.accountsPartial({
-				admin: admin.publicKey,
-				adminUsdcAccount: adminUsdcAccount.address,
+				treasurer: admin.publicKey,
+				treasurerUsdcAccount: adminUsdcAccount.address,
 				property: propertyPda,
 				// propertyUsdcAccount: propertyUsdcVault.address,
 				tokenProgram: TOKEN_PROGRAM_ID,
@@ -410,14 +533,25 @@ describe("RWA Platform", async () => {
 			program.programId
 		);
 
+		const year = new Date().getUTCFullYear();
+		const yearBuffer = Buffer.alloc(2);
+		yearBuffer.writeUInt16LE(year);
+		const [taxRecord] = PublicKey.findProgramAddressSync(
+			[Buffer.from("tax_record"), investmentAccount.toBuffer(), yearBuffer],
+			program.programId
+		);
+
 		await program.methods
-			.redeemDividends()
+			.redeemDividends(year)
 			.accountsPartial({
+				usdcMint,
 				propertyUsdcAccount: propertyUsdcVault.address,
 				investor: investor.publicKey,
 				investorUsdcAccount: investorUsdcAccount.address,
+				taxVault: adminUsdcAccount.address,
 				property: propertyPda,
 				investmentAccount,
+				taxRecord,
 				tokenProgram: TOKEN_PROGRAM_ID,
 				systemProgram: SystemProgram.programId,
 			})
@@ -442,6 +576,54 @@ describe("RWA Platform", async () => {
 			investmentAccountData.dividendsClaimed.toNumber(),
 			30 * 10 ** 6
 		);
+
+		const taxRecordData = await program.account.taxRecord.fetch(taxRecord);
+		assert.equal(taxRecordData.year, year);
+		assert.equal(taxRecordData.grossDividends.toNumber(), 30 * 10 ** 6);
+		assert.equal(taxRecordData.taxWithheld.toNumber(), 0);
+	});
+
+	it("Sets a withholding rate as the compliance authority", async () => {
+		const [complianceRecord] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("compliance"),
+				propertyPda.toBuffer(),
+				investor.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		try {
+			await program.methods
+				.setWithholdingRate(3_000)
+				.accountsPartial({
+					compliance: investor.publicKey,
+					payer: investor.publicKey,
+					property: propertyPda,
+					wallet: investor.publicKey,
+				})
+				.signers([investor])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.isDefined(error);
+		}
+
+		await program.methods
+			.setWithholdingRate(3_000)
+			.accountsPartial({
+				compliance: admin.publicKey,
+				payer: admin.publicKey,
+				property: propertyPda,
+				wallet: investor.publicKey,
+			})
+			.signers([admin])
+			.rpc();
+
+		const complianceRecordData = await program.account.complianceRecord.fetch(
+			complianceRecord
+		);
+		assert.equal(complianceRecordData.withholdingRateBps, 3_000);
 	});
 
 	it("Withdraws investment from a property!", async () => {
@@ -459,9 +641,41 @@ describe("RWA Platform", async () => {
 		);
 		assert.equal(initialUsdcBalance.value.amount, "730000000");
 
+		const withdrawAccounts = {
+			usdcMint,
+			propertyUsdcAccount: propertyUsdcVault.address,
+			propertyVault: propertyVault.address,
+			investor: investor.publicKey,
+			investorUsdcAccount: investorUsdcAccount.address,
+			investmentAccount: investmentAccount,
+			property: propertyPda,
+			propertyMint: propertyMint,
+			adminUsdcAccount: adminUsdcAccount.address,
+			investorPropertyTokenAccount: investorPropertyTokenAccount.address,
+			systemProgram: SystemProgram.programId,
+			tokenProgram: TOKEN_PROGRAM_ID,
+		};
+
+		await program.methods
+			.withdrawInvestment(new anchor.BN(1))
+			.accountsPartial(withdrawAccounts)
+			.signers([investor])
+			.rpc();
+
+		const partialUsdcBalance = await connection.getTokenAccountBalance(
+			investorUsdcAccount.address
+		);
+		assert.equal(partialUsdcBalance.value.amount, "830000000");
+
+		const partialInvestmentAccount = await program.account.investor.fetch(
+			investmentAccount
+		);
+		assert.equal(partialInvestmentAccount.tokensOwned.toNumber(), 2);
+
 		await program.methods
-			.withdrawInvestment()
+			.withdrawInvestment(new anchor.BN(2))
 			.accountsPartial({
+				usdcMint,
 				propertyUsdcAccount: propertyUsdcVault.address,
 				propertyVault: propertyVault.address,
 				investor: investor.publicKey,
@@ -502,6 +716,20 @@ describe("RWA Platform", async () => {
 			investmentAccountInfo,
 			"Investment account should be closed"
 		);
+
+		// The withholding rate outlives the position, so re-entering can't reset it.
+		const [complianceRecord] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("compliance"),
+				propertyPda.toBuffer(),
+				investor.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+		const complianceRecordData = await program.account.complianceRecord.fetch(
+			complianceRecord
+		);
+		assert.equal(complianceRecordData.withholdingRateBps, 3_000);
 	});
 
 	it("Mint additional tokens for a property", async () => {
@@ -510,7 +738,7 @@ describe("RWA Platform", async () => {
 		await program.methods
 			.mintAdditionalTokens(new anchor.BN(additionalAmount))
 			.accountsPartial({
-				admin: admin.publicKey,
+				treasurer: admin.publicKey,
 				property: propertyPda,
 				propertyMint: propertyMint,
 				tokenProgram: TOKEN_PROGRAM_ID,
@@ -545,8 +773,13 @@ describe("RWA Platform", async () => {
 
 		try {
 			const tx = program.methods
-				.investInProperty(new anchor.BN(300 * 10 ** 6))
+				.investInProperty(
+					new anchor.BN(3),
+					pricePerToken,
+					new anchor.BN((await connection.getSlot()) + 100)
+				)
 				.accountsPartial({
+					usdcMint,
 					investor: investor.publicKey,
 					property: propertyPda,
 					investorUsdcAccount: investorUsdcAccount.address,
@@ -557,6 +790,10 @@ describe("RWA Platform", async () => {
 					investorPropertyTokenAccount:
 						investorPropertyTokenAccount.address,
 					investmentAccount: investmentAccount,
+					investmentRecord: await findInvestmentRecordPda(
+						program,
+						propertyPda
+					),
 				})
 				.signers([investor /*, admin*/])
 				.rpc();
@@ -593,6 +830,7 @@ describe("RWA Platform", async () => {
 				propertyMint,
 				propertyVault: propertyVault.address,
 				property: propertyPda,
+				operator: admin.publicKey,
 				admin: admin.publicKey,
 				adminUsdcAccount: adminUsdcAccount.address,
 				tokenProgram: TOKEN_PROGRAM_ID,
@@ -758,6 +996,7 @@ describe("Transfering", async () => {
 				admin: admin.publicKey,
 				property: propertyPda,
 				propertyMint,
+				usdcMint,
 				systemProgram: SystemProgram.programId,
 				tokenProgram: TOKEN_PROGRAM_ID,
 			})
@@ -791,8 +1030,13 @@ describe("Transfering", async () => {
 		);
 
 		await program.methods
-			.investInProperty(new anchor.BN(300 * 10 ** 6))
+			.investInProperty(
+				new anchor.BN(3),
+				pricePerToken,
+				new anchor.BN((await connection.getSlot()) + 100)
+			)
 			.accountsPartial({
+				usdcMint,
 				investor: investor.publicKey,
 				property: propertyPda,
 				investorUsdcAccount: investorUsdcAccount.address,
@@ -803,6 +1047,10 @@ describe("Transfering", async () => {
 				investorPropertyTokenAccount:
 					investorPropertyTokenAccount.address,
 				investmentAccount,
+				investmentRecord: await findInvestmentRecordPda(
+					program,
+					propertyPda
+				),
 				propertyUsdcAccount: propertyUsdcVault.address,
 				propertyVault: propertyVault.address,
 			})
@@ -852,6 +1100,23 @@ describe("Transfering", async () => {
 				toTokenAccount: anotherInvestorPropertyTokenAccount.address,
 				to: anotherInvestor.publicKey,
 				propertyMint: propertyMint,
+				property: propertyPda,
+				fromInvestmentAccount: PublicKey.findProgramAddressSync(
+					[
+						Buffer.from("investment"),
+						investor.publicKey.toBuffer(),
+						propertyPda.toBuffer(),
+					],
+					program.programId
+				)[0],
+				toInvestmentAccount: PublicKey.findProgramAddressSync(
+					[
+						Buffer.from("investment"),
+						anotherInvestor.publicKey.toBuffer(),
+						propertyPda.toBuffer(),
+					],
+					program.programId
+				)[0],
 				tokenProgram: TOKEN_PROGRAM_ID,
 				systemProgram: SystemProgram.programId,
 			})
@@ -876,173 +1141,3729 @@ describe("Transfering", async () => {
 			"Another investor should have 1 token after transfer"
 		);
 	});
-});
-
-describe("Governance", async () => {
-	const provider = anchor.AnchorProvider.env();
-	anchor.setProvider(provider);
-	const connection = provider.connection;
-
-	const program = anchor.workspace.CrowdEstate as Program<CrowdEstate>;
 
-	let property: PublicKey;
-	let propertyMint: PublicKey;
-	let proposal: PublicKey;
-	let bump: number;
-	let proposer = Keypair.generate();
-	let voter = Keypair.generate();
+	it("Blocks transfers while paused or frozen", async () => {
+		const recipient = Keypair.generate();
+		const recipientPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				admin,
+				propertyMint,
+				recipient.publicKey
+			);
+		const transfer = () =>
+			program.methods
+				.transferTokens(new anchor.BN(1))
+				.accountsPartial({
+					authority: investor.publicKey,
+					fromTokenAccount: investorPropertyTokenAccount.address,
+					toTokenAccount: recipientPropertyTokenAccount.address,
+					to: recipient.publicKey,
+					propertyMint,
+					property: propertyPda,
+				})
+				.signers([investor])
+				.rpc();
+		const setWalletCompliance = (frozen: boolean) =>
+			program.methods
+				.setWalletCompliance(true, frozen, new anchor.BN(0))
+				.accountsPartial({
+					compliance: admin.publicKey,
+					payer: admin.publicKey,
+					property: propertyPda,
+					wallet: investor.publicKey,
+				})
+				.signers([admin])
+				.rpc();
+		const setPaused = (paused: boolean) =>
+			program.methods
+				.setPaused(paused)
+				.accountsPartial({ guardian: admin.publicKey, property: propertyPda })
+				.signers([admin])
+				.rpc();
 
-	before(async () => {
-		const latestBlockhash = await connection.getLatestBlockhash();
+		await setPaused(true);
+		try {
+			await transfer();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "PropertyPaused");
+		}
+		await setPaused(false);
 
-		await connection.confirmTransaction({
-			signature: await connection.requestAirdrop(
-				proposer.publicKey,
-				5 * anchor.web3.LAMPORTS_PER_SOL
-			),
-			...latestBlockhash,
-		});
+		await setWalletCompliance(true);
+		try {
+			await transfer();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "WalletFrozen");
+		}
+		await setWalletCompliance(false);
 
-		await connection.confirmTransaction({
-			signature: await connection.requestAirdrop(
-				voter.publicKey,
-				5 * anchor.web3.LAMPORTS_PER_SOL
-			),
-			...latestBlockhash,
-		});
+		const complianceRecord = await program.account.complianceRecord.fetch(
+			PublicKey.findProgramAddressSync(
+				[
+					Buffer.from("compliance"),
+					propertyPda.toBuffer(),
+					investor.publicKey.toBuffer(),
+				],
+				program.programId
+			)[0]
+		);
+		assert.isFalse(complianceRecord.frozen);
+		assert.isTrue(complianceRecord.kycVerified);
+	});
 
-		[property, bump] = PublicKey.findProgramAddressSync(
+	it("Tops up an existing position at the early investor tranche price", async () => {
+		const [investmentAccount] = PublicKey.findProgramAddressSync(
 			[
-				Buffer.from("property"),
-				proposer.publicKey.toBuffer(),
-				Buffer.from("Test Property"),
+				Buffer.from("investment"),
+				investor.publicKey.toBuffer(),
+				propertyPda.toBuffer(),
 			],
 			program.programId
 		);
-
-		propertyMint = await createMint(
-			connection,
-			proposer,
-			property,
-			null,
-			0
+		const investmentRecord = await findInvestmentRecordPda(
+			program,
+			propertyPda
 		);
 
-		[proposal] = PublicKey.findProgramAddressSync(
-			[
-				Buffer.from("proposal"),
-				proposer.publicKey.toBuffer(),
-				property.toBuffer(),
-			],
-			program.programId
-		);
+		const costBasisBefore = (
+			await program.account.investor.fetch(investmentAccount)
+		).costBasisUsdc.toNumber();
 
 		await program.methods
-			.createProperty(
-				"Test Property",
-				new anchor.BN(100),
-				new anchor.BN(100 * 10 ** 6),
-				"TST",
-				bump
-			)
-			.accountsPartial({
-				property: property,
-				admin: proposer.publicKey,
-				systemProgram: SystemProgram.programId,
-				propertyMint: propertyMint,
-				tokenProgram: TOKEN_PROGRAM_ID,
-			})
-			.signers([proposer])
+			.setPriceTranches([[new anchor.BN(1), new anchor.BN(50 * 10 ** 6)]])
+			.accountsPartial({ admin: admin.publicKey, property: propertyPda })
+			.signers([admin])
 			.rpc();
 
-		const propertyAccount = await program.account.property.fetch(property);
-		assert.include(
-			Buffer.from(propertyAccount.propertyName).toString(),
-			"Test Property"
-		);
-		assert.equal(Number(propertyAccount.totalTokens), 100);
-		assert.equal(Number(propertyAccount.availableTokens), 100);
-		assert.equal(propertyAccount.isClosed, false);
-	});
-
-	it("Creates a proposal", async () => {
 		await program.methods
-			.createProposal(
-				"Test Proposal",
-				{ mintAdditionalTokens: {} },
-				"",
-				new anchor.BN(100)
+			.investInProperty(
+				new anchor.BN(1),
+				pricePerToken,
+				new anchor.BN((await connection.getSlot()) + 100)
 			)
 			.accountsPartial({
-				proposal: proposal,
-				proposer: proposer.publicKey,
-				property: property,
+				usdcMint,
+				investor: investor.publicKey,
+				property: propertyPda,
+				investorUsdcAccount: investorUsdcAccount.address,
+				tokenProgram: TOKEN_PROGRAM_ID,
 				systemProgram: SystemProgram.programId,
+				propertyMint,
+				investorPropertyTokenAccount:
+					investorPropertyTokenAccount.address,
+				investmentAccount,
+				investmentRecord,
+				propertyUsdcAccount: propertyUsdcVault.address,
+				propertyVault: propertyVault.address,
 			})
-			.signers([proposer])
+			.signers([investor])
 			.rpc();
 
-		const proposalAccount = await program.account.proposal.fetch(proposal);
-		assert.include(
-			Buffer.from(proposalAccount.description).toString(),
-			"Test Proposal"
+		const investmentAccountData = await program.account.investor.fetch(
+			investmentAccount
 		);
-		assert.equal(Number(proposalAccount.votesFor), 0);
-		assert.equal(Number(proposalAccount.votesAgainst), 0);
-		assert.equal(proposalAccount.isExecuted, false);
-	});
+		assert.equal(investmentAccountData.tokensOwned.toNumber(), 3);
+
+		const investmentRecordData =
+			await program.account.investmentRecord.fetch(investmentRecord);
+		assert.equal(investmentRecordData.index.toNumber(), 1);
+		assert.equal(investmentRecordData.tokens.toNumber(), 1);
+		assert.equal(
+			investmentRecordData.tokenPriceUsdc.toNumber(),
+			50 * 10 ** 6
+		);
+		assert.equal(investmentAccountData.trancheTokens[0].toNumber(), 1);
+		// The tranche token only adds what was paid for it, which caps what
+		// it can be withdrawn for.
+		assert.equal(
+			investmentAccountData.costBasisUsdc.toNumber() - costBasisBefore,
+			50 * 10 ** 6
+		);
+
+		const propertyAccount = await program.account.property.fetch(
+			propertyPda
+		);
+		assert.equal(propertyAccount.priceTranches[0].tokensSold.toNumber(), 1);
+	});
+
+	it("Pays the referrer a share of the platform fee", async () => {
+		const referred = Keypair.generate();
+		const latestBlockhash = await connection.getLatestBlockhash();
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				referred.publicKey,
+				5 * anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+
+		const referredUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			admin,
+			usdcMint,
+			referred.publicKey
+		);
+		await mintTo(
+			connection,
+			admin,
+			usdcMint,
+			referredUsdcAccount.address,
+			admin,
+			1_000 * 10 ** 6
+		);
+		const referredPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				admin,
+				propertyMint,
+				referred.publicKey
+			);
+
+		const [referrerStats] = PublicKey.findProgramAddressSync(
+			[Buffer.from("referrer"), investor.publicKey.toBuffer()],
+			program.programId
+		);
+		const [investmentAccount] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				referred.publicKey.toBuffer(),
+				propertyPda.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.setPlatformFee(100, 5_000)
+			.accountsPartial({ admin: admin.publicKey, property: propertyPda })
+			.signers([admin])
+			.rpc();
+
+		await program.methods
+			.registerReferrer()
+			.accountsPartial({
+				referrer: investor.publicKey,
+				referrerStats,
+				referrerUsdcAccount: investorUsdcAccount.address,
+			})
+			.signers([investor])
+			.rpc();
+
+		const referrerBalanceBefore = await connection.getTokenAccountBalance(
+			investorUsdcAccount.address
+		);
+
+		const invest = async (maxTokenPrice: anchor.BN, withReferrer = true) =>
+			program.methods
+				.investInProperty(
+					new anchor.BN(1),
+					maxTokenPrice,
+					new anchor.BN((await connection.getSlot()) + 100)
+				)
+				.accountsPartial({
+					usdcMint,
+					investor: referred.publicKey,
+					property: propertyPda,
+					investorUsdcAccount: referredUsdcAccount.address,
+					propertyMint,
+					investorPropertyTokenAccount:
+						referredPropertyTokenAccount.address,
+					investmentAccount,
+					investmentRecord: await findInvestmentRecordPda(
+						program,
+						propertyPda
+					),
+					propertyUsdcAccount: propertyUsdcVault.address,
+					propertyVault: propertyVault.address,
+					platformFeeAccount: adminUsdcAccount.address,
+					referrerStats: withReferrer ? referrerStats : null,
+					referrerUsdcAccount: withReferrer
+						? investorUsdcAccount.address
+						: null,
+				})
+				.signers([referred])
+				.rpc();
+
+		// The 1% platform fee comes on top of the price, so the limit must cover it.
+		try {
+			await invest(pricePerToken);
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "PriceAboveLimit");
+		}
+
+		await invest(pricePerToken.muln(101).divn(100));
+
+		const referredUsdcBalance = await connection.getTokenAccountBalance(
+			referredUsdcAccount.address
+		);
+		assert.equal(referredUsdcBalance.value.amount, "899000000");
+
+		const referrerBalanceAfter = await connection.getTokenAccountBalance(
+			investorUsdcAccount.address
+		);
+		assert.equal(
+			Number(referrerBalanceAfter.value.amount) -
+				Number(referrerBalanceBefore.value.amount),
+			500_000
+		);
+
+		const investmentAccountData = await program.account.investor.fetch(
+			investmentAccount
+		);
+		assert.equal(
+			investmentAccountData.referrer.toBase58(),
+			investor.publicKey.toBase58()
+		);
+
+		const referrerStatsData = await program.account.referrerStats.fetch(
+			referrerStats
+		);
+		assert.equal(referrerStatsData.referredInvestors.toNumber(), 1);
+		assert.equal(referrerStatsData.feesEarned.toNumber(), 500_000);
+
+		// Without the referrer's accounts the purchase still goes through and
+		// the platform keeps the whole fee.
+		const platformBalanceBefore = await connection.getTokenAccountBalance(
+			adminUsdcAccount.address
+		);
+		await invest(pricePerToken.muln(101).divn(100), false);
+		const platformBalanceAfter = await connection.getTokenAccountBalance(
+			adminUsdcAccount.address
+		);
+		assert.equal(
+			Number(platformBalanceAfter.value.amount) -
+				Number(platformBalanceBefore.value.amount),
+			1_000_000
+		);
+		assert.equal(
+			(
+				await connection.getTokenAccountBalance(investorUsdcAccount.address)
+			).value.amount,
+			referrerBalanceAfter.value.amount
+		);
+
+		await program.methods
+			.setPlatformFee(0, 0)
+			.accountsPartial({ admin: admin.publicKey, property: propertyPda })
+			.signers([admin])
+			.rpc();
+	});
+
+	it("Lists tokens and fills the listing", async () => {
+		const buyer = Keypair.generate();
+		const latestBlockhash = await connection.getLatestBlockhash();
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				buyer.publicKey,
+				5 * anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+
+		const buyerUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			admin,
+			usdcMint,
+			buyer.publicKey
+		);
+		await mintTo(
+			connection,
+			admin,
+			usdcMint,
+			buyerUsdcAccount.address,
+			admin,
+			1_000 * 10 ** 6
+		);
+		const buyerPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				admin,
+				propertyMint,
+				buyer.publicKey
+			);
+
+		const [sellerInvestmentAccount] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				investor.publicKey.toBuffer(),
+				propertyPda.toBuffer(),
+			],
+			program.programId
+		);
+		const [buyerInvestmentAccount] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				buyer.publicKey.toBuffer(),
+				propertyPda.toBuffer(),
+			],
+			program.programId
+		);
+		const [listing] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("listing"),
+				propertyPda.toBuffer(),
+				investor.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.createListing(new anchor.BN(2), new anchor.BN(150 * 10 ** 6))
+			.accountsPartial({
+				seller: investor.publicKey,
+				property: propertyPda,
+				propertyMint,
+				sellerInvestmentAccount,
+				sellerPropertyTokenAccount: investorPropertyTokenAccount.address,
+				usdcMint,
+				listing,
+			})
+			.signers([investor])
+			.rpc();
+
+		const junkMint = await createMint(
+			connection,
+			admin,
+			admin.publicKey,
+			null,
+			6
+		);
+		const sellerJunkAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			admin,
+			junkMint,
+			investor.publicKey
+		);
+		const buyerJunkAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			admin,
+			junkMint,
+			buyer.publicKey
+		);
+		await mintTo(
+			connection,
+			admin,
+			junkMint,
+			buyerJunkAccount.address,
+			admin,
+			1_000 * 10 ** 6
+		);
+		try {
+			await program.methods
+				.fillListing(new anchor.BN(1), new anchor.BN(150 * 10 ** 6))
+				.accountsPartial({
+					buyer: buyer.publicKey,
+					seller: investor.publicKey,
+					property: propertyPda,
+					propertyMint,
+					listing,
+					sellerInvestmentAccount,
+					buyerInvestmentAccount,
+					sellerUsdcAccount: sellerJunkAccount.address,
+					buyerUsdcAccount: buyerJunkAccount.address,
+					buyerPropertyTokenAccount: buyerPropertyTokenAccount.address,
+				})
+				.signers([buyer])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "InvalidUsdcAccount");
+		}
+
+		await program.methods
+			.fillListing(new anchor.BN(1), new anchor.BN(150 * 10 ** 6))
+			.accountsPartial({
+				buyer: buyer.publicKey,
+				seller: investor.publicKey,
+				property: propertyPda,
+				propertyMint,
+				listing,
+				sellerInvestmentAccount,
+				buyerInvestmentAccount,
+				sellerUsdcAccount: investorUsdcAccount.address,
+				buyerUsdcAccount: buyerUsdcAccount.address,
+				buyerPropertyTokenAccount: buyerPropertyTokenAccount.address,
+			})
+			.signers([buyer])
+			.rpc();
+
+		const listingData = await program.account.listing.fetch(listing);
+		assert.equal(listingData.tokensRemaining.toNumber(), 1);
+
+		const sellerData = await program.account.investor.fetch(
+			sellerInvestmentAccount
+		);
+		assert.equal(sellerData.tokensOwned.toNumber(), 2);
+		assert.equal(sellerData.tokensListed.toNumber(), 1);
+
+		const buyerData = await program.account.investor.fetch(
+			buyerInvestmentAccount
+		);
+		assert.equal(buyerData.tokensOwned.toNumber(), 1);
+
+		const buyerTokenBalance = await connection.getTokenAccountBalance(
+			buyerPropertyTokenAccount.address
+		);
+		assert.equal(buyerTokenBalance.value.amount, "1");
+
+		await program.methods
+			.cancelListing()
+			.accountsPartial({
+				payer: investor.publicKey,
+				seller: investor.publicKey,
+				property: propertyPda,
+				propertyMint,
+				listing,
+				sellerInvestmentAccount,
+				sellerPropertyTokenAccount: investorPropertyTokenAccount.address,
+			})
+			.signers([investor])
+			.rpc();
+
+		assert.isNull(await connection.getAccountInfo(listing));
+	});
+
+	it("Matches orders on the order book", async () => {
+		const buyer = Keypair.generate();
+		const latestBlockhash = await connection.getLatestBlockhash();
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				buyer.publicKey,
+				5 * anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+
+		const buyerUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			admin,
+			usdcMint,
+			buyer.publicKey
+		);
+		await mintTo(
+			connection,
+			admin,
+			usdcMint,
+			buyerUsdcAccount.address,
+			admin,
+			1_000 * 10 ** 6
+		);
+		const buyerPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				admin,
+				propertyMint,
+				buyer.publicKey
+			);
+
+		const [orderBook] = PublicKey.findProgramAddressSync(
+			[Buffer.from("order_book"), propertyPda.toBuffer()],
+			program.programId
+		);
+		const [sellerInvestmentAccount] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				investor.publicKey.toBuffer(),
+				propertyPda.toBuffer(),
+			],
+			program.programId
+		);
+		const [sellerComplianceRecord] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("compliance"),
+				propertyPda.toBuffer(),
+				investor.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+		const sellerAccounts = [
+			{ pubkey: sellerInvestmentAccount, isSigner: false, isWritable: true },
+			{ pubkey: sellerComplianceRecord, isSigner: false, isWritable: false },
+		];
+		const placeSellerAsk = () =>
+			program.methods
+				.placeOrder({ ask: {} }, new anchor.BN(120 * 10 ** 6), new anchor.BN(1))
+				.accountsPartial({
+					owner: investor.publicKey,
+					property: propertyPda,
+					propertyMint,
+					orderBook,
+					ownerInvestmentAccount: sellerInvestmentAccount,
+					ownerPropertyTokenAccount: investorPropertyTokenAccount.address,
+					ownerUsdcAccount: investorUsdcAccount.address,
+				})
+				.signers([investor])
+				.rpc();
+		const settleSeller = () =>
+			program.methods
+				.settleOrder()
+				.accountsPartial({
+					payer: investor.publicKey,
+					owner: investor.publicKey,
+					property: propertyPda,
+					propertyMint,
+					orderBook,
+					ownerInvestmentAccount: sellerInvestmentAccount,
+					ownerPropertyTokenAccount: investorPropertyTokenAccount.address,
+					ownerUsdcAccount: investorUsdcAccount.address,
+				})
+				.signers([investor])
+				.rpc();
+
+		await program.methods
+			.initializeOrderBook()
+			.accountsPartial({
+				admin: admin.publicKey,
+				payer: admin.publicKey,
+				property: propertyPda,
+				propertyMint,
+				usdcMint,
+				orderBook,
+			})
+			.signers([admin])
+			.rpc();
+
+		await placeSellerAsk();
+
+		await program.methods
+			.placeOrder({ bid: {} }, new anchor.BN(130 * 10 ** 6), new anchor.BN(1))
+			.accountsPartial({
+				owner: buyer.publicKey,
+				property: propertyPda,
+				propertyMint,
+				orderBook,
+				ownerPropertyTokenAccount: buyerPropertyTokenAccount.address,
+				ownerUsdcAccount: buyerUsdcAccount.address,
+			})
+			.remainingAccounts(sellerAccounts)
+			.signers([buyer])
+			.rpc();
+
+		const buyerUsdcBalance = await connection.getTokenAccountBalance(
+			buyerUsdcAccount.address
+		);
+		assert.equal(buyerUsdcBalance.value.amount, "880000000");
+
+		// The filled ask leaves the book; its proceeds wait on the seller's
+		// position.
+		const orderBookData = await program.account.orderBook.fetch(orderBook);
+		assert.equal(orderBookData.bids.length, 0);
+		assert.equal(orderBookData.asks.length, 0);
+		assert.equal(orderBookData.unsettledQuote.toNumber(), 120 * 10 ** 6);
+		assert.equal(
+			(
+				await program.account.investor.fetch(sellerInvestmentAccount)
+			).orderQuoteOwed.toNumber(),
+			120 * 10 ** 6
+		);
+
+		await settleSeller();
+
+		const settledOrderBook = await program.account.orderBook.fetch(
+			orderBook
+		);
+		assert.equal(settledOrderBook.unsettledQuote.toNumber(), 0);
+		assert.equal(
+			(
+				await program.account.investor.fetch(sellerInvestmentAccount)
+			).orderQuoteOwed.toNumber(),
+			0
+		);
+
+		try {
+			await program.methods
+				.placeOrder({ bid: {} }, new anchor.BN(1 * 10 ** 6), new anchor.BN(1))
+				.accountsPartial({
+					owner: buyer.publicKey,
+					property: propertyPda,
+					propertyMint,
+					orderBook,
+					ownerPropertyTokenAccount: buyerPropertyTokenAccount.address,
+					ownerUsdcAccount: buyerUsdcAccount.address,
+				})
+				.signers([buyer])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "OrderTooSmall");
+		}
+
+		const [buyerInvestmentAccount] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				buyer.publicKey.toBuffer(),
+				propertyPda.toBuffer(),
+			],
+			program.programId
+		);
+		await program.methods
+			.placeOrder({ bid: {} }, new anchor.BN(100 * 10 ** 6), new anchor.BN(1))
+			.accountsPartial({
+				owner: buyer.publicKey,
+				property: propertyPda,
+				propertyMint,
+				orderBook,
+				ownerPropertyTokenAccount: buyerPropertyTokenAccount.address,
+				ownerUsdcAccount: buyerUsdcAccount.address,
+			})
+			.signers([buyer])
+			.rpc();
+
+		try {
+			await program.methods
+				.placeOrder({ ask: {} }, new anchor.BN(90 * 10 ** 6), new anchor.BN(1))
+				.accountsPartial({
+					owner: buyer.publicKey,
+					property: propertyPda,
+					propertyMint,
+					orderBook,
+					ownerPropertyTokenAccount: buyerPropertyTokenAccount.address,
+					ownerUsdcAccount: buyerUsdcAccount.address,
+				})
+				.signers([buyer])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "SelfCrossingOrder");
+		}
+
+		const restingBid = (await program.account.orderBook.fetch(orderBook))
+			.bids[0];
+		await program.methods
+			.cancelOrder(restingBid.orderId)
+			.accountsPartial({
+				payer: buyer.publicKey,
+				owner: buyer.publicKey,
+				property: propertyPda,
+				propertyMint,
+				orderBook,
+				ownerInvestmentAccount: buyerInvestmentAccount,
+				ownerPropertyTokenAccount: buyerPropertyTokenAccount.address,
+				ownerUsdcAccount: buyerUsdcAccount.address,
+			})
+			.signers([buyer])
+			.rpc();
+
+		const finalOrderBook = await program.account.orderBook.fetch(orderBook);
+		assert.equal(finalOrderBook.bids.length, 0);
+
+		// A maker frozen after placing its ask is taken off the book when a
+		// taker reaches it, and its tokens are released to its position.
+		const setSellerFrozen = (frozen: boolean) =>
+			program.methods
+				.setWalletCompliance(true, frozen, new anchor.BN(0))
+				.accountsPartial({
+					compliance: admin.publicKey,
+					payer: admin.publicKey,
+					property: propertyPda,
+					wallet: investor.publicKey,
+				})
+				.signers([admin])
+				.rpc();
+		await placeSellerAsk();
+		await setSellerFrozen(true);
+		await program.methods
+			.placeOrder({ bid: {} }, new anchor.BN(130 * 10 ** 6), new anchor.BN(1))
+			.accountsPartial({
+				owner: buyer.publicKey,
+				property: propertyPda,
+				propertyMint,
+				orderBook,
+				ownerPropertyTokenAccount: buyerPropertyTokenAccount.address,
+				ownerUsdcAccount: buyerUsdcAccount.address,
+			})
+			.remainingAccounts(sellerAccounts)
+			.signers([buyer])
+			.rpc();
+		await setSellerFrozen(false);
+
+		const releasedOrderBook = await program.account.orderBook.fetch(
+			orderBook
+		);
+		assert.equal(releasedOrderBook.asks.length, 0);
+		assert.equal(releasedOrderBook.bids.length, 1);
+		const sellerPosition = await program.account.investor.fetch(
+			sellerInvestmentAccount
+		);
+		assert.equal(sellerPosition.orderBaseOwed.toNumber(), 1);
+		assert.equal(sellerPosition.tokensListed.toNumber(), 0);
+
+		await settleSeller();
+		await program.methods
+			.cancelOrder(releasedOrderBook.bids[0].orderId)
+			.accountsPartial({
+				payer: buyer.publicKey,
+				owner: buyer.publicKey,
+				property: propertyPda,
+				propertyMint,
+				orderBook,
+				ownerInvestmentAccount: buyerInvestmentAccount,
+				ownerPropertyTokenAccount: buyerPropertyTokenAccount.address,
+				ownerUsdcAccount: buyerUsdcAccount.address,
+			})
+			.signers([buyer])
+			.rpc();
+
+		const emptyOrderBook = await program.account.orderBook.fetch(orderBook);
+		assert.equal(emptyOrderBook.bids.length, 0);
+		assert.equal(emptyOrderBook.unsettledBase.toNumber(), 0);
+	});
+
+	it("Provides liquidity to the property pool", async () => {
+		const [pool] = PublicKey.findProgramAddressSync(
+			[Buffer.from("pool"), propertyPda.toBuffer()],
+			program.programId
+		);
+		const [lpPosition] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("lp_position"),
+				pool.toBuffer(),
+				investor.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+		const [providerInvestmentAccount] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				investor.publicKey.toBuffer(),
+				propertyPda.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.initializePool(30)
+			.accountsPartial({
+				admin: admin.publicKey,
+				payer: admin.publicKey,
+				property: propertyPda,
+				propertyMint,
+				usdcMint,
+				pool,
+			})
+			.signers([admin])
+			.rpc();
+
+		await program.methods
+			.addLiquidity(
+				new anchor.BN(1),
+				new anchor.BN(100 * 10 ** 6),
+				new anchor.BN(0)
+			)
+			.accountsPartial({
+				provider: investor.publicKey,
+				property: propertyPda,
+				propertyMint,
+				pool,
+				providerInvestmentAccount,
+				lpPosition,
+				providerPropertyTokenAccount: investorPropertyTokenAccount.address,
+				providerUsdcAccount: investorUsdcAccount.address,
+			})
+			.signers([investor])
+			.rpc();
+
+		const poolData = await program.account.pool.fetch(pool);
+		assert.equal(poolData.baseReserve.toNumber(), 1);
+		assert.equal(poolData.quoteReserve.toNumber(), 100 * 10 ** 6);
+
+		const lpPositionData = await program.account.lpPosition.fetch(
+			lpPosition
+		);
+		assert.equal(
+			lpPositionData.shares.toNumber(),
+			poolData.totalShares.toNumber()
+		);
+	});
+
+	it("Swaps against the pool and pays LPs the dividends their pooled tokens earn", async () => {
+		const [pool] = PublicKey.findProgramAddressSync(
+			[Buffer.from("pool"), propertyPda.toBuffer()],
+			program.programId
+		);
+		const findLpPosition = (owner: PublicKey) =>
+			PublicKey.findProgramAddressSync(
+				[Buffer.from("lp_position"), pool.toBuffer(), owner.toBuffer()],
+				program.programId
+			)[0];
+		const findInvestment = (owner: PublicKey) =>
+			PublicKey.findProgramAddressSync(
+				[Buffer.from("investment"), owner.toBuffer(), propertyPda.toBuffer()],
+				program.programId
+			)[0];
+		const fundWallet = async (wallet: Keypair) => {
+			const latestBlockhash = await connection.getLatestBlockhash();
+			await connection.confirmTransaction({
+				signature: await connection.requestAirdrop(
+					wallet.publicKey,
+					5 * anchor.web3.LAMPORTS_PER_SOL
+				),
+				...latestBlockhash,
+			});
+			const usdcAccount = await getOrCreateAssociatedTokenAccount(
+				connection,
+				admin,
+				usdcMint,
+				wallet.publicKey
+			);
+			await mintTo(
+				connection,
+				admin,
+				usdcMint,
+				usdcAccount.address,
+				admin,
+				1_000 * 10 ** 6
+			);
+			const propertyTokenAccount = await getOrCreateAssociatedTokenAccount(
+				connection,
+				admin,
+				propertyMint,
+				wallet.publicKey
+			);
+			return { usdcAccount, propertyTokenAccount };
+		};
+
+		// A second provider buys 4 tokens and pools them at the pool's price.
+		const lp = Keypair.generate();
+		const lpAccounts = await fundWallet(lp);
+		await program.methods
+			.investInProperty(
+				new anchor.BN(4),
+				pricePerToken,
+				new anchor.BN((await connection.getSlot()) + 100)
+			)
+			.accountsPartial({
+				usdcMint,
+				investor: lp.publicKey,
+				property: propertyPda,
+				investorUsdcAccount: lpAccounts.usdcAccount.address,
+				propertyMint,
+				investorPropertyTokenAccount: lpAccounts.propertyTokenAccount.address,
+				investmentAccount: findInvestment(lp.publicKey),
+				investmentRecord: await findInvestmentRecordPda(program, propertyPda),
+				propertyUsdcAccount: propertyUsdcVault.address,
+				propertyVault: propertyVault.address,
+			})
+			.signers([lp])
+			.rpc();
+		await program.methods
+			.addLiquidity(
+				new anchor.BN(4),
+				new anchor.BN(401 * 10 ** 6),
+				new anchor.BN(0)
+			)
+			.accountsPartial({
+				provider: lp.publicKey,
+				property: propertyPda,
+				propertyMint,
+				pool,
+				providerInvestmentAccount: findInvestment(lp.publicKey),
+				lpPosition: findLpPosition(lp.publicKey),
+				providerPropertyTokenAccount: lpAccounts.propertyTokenAccount.address,
+				providerUsdcAccount: lpAccounts.usdcAccount.address,
+			})
+			.signers([lp])
+			.rpc();
+
+		let poolData = await program.account.pool.fetch(pool);
+		assert.equal(poolData.baseReserve.toNumber(), 5);
+		assert.equal(
+			(await program.account.lpPosition.fetch(findLpPosition(lp.publicKey)))
+				.shares.toNumber() * 5,
+			poolData.totalShares.toNumber() * 4
+		);
+
+		// Without the 0.3% fee this would buy 2 tokens; after it, only 1.
+		const trader = Keypair.generate();
+		const traderAccounts = await fundWallet(trader);
+		const amountIn = new anchor.BN(334 * 10 ** 6);
+		const swap = (minAmountOut: number) =>
+			program.methods
+				.swap({ bid: {} }, amountIn, new anchor.BN(minAmountOut))
+				.accountsPartial({
+					trader: trader.publicKey,
+					property: propertyPda,
+					propertyMint,
+					pool,
+					traderInvestmentAccount: findInvestment(trader.publicKey),
+					traderPropertyTokenAccount:
+						traderAccounts.propertyTokenAccount.address,
+					traderUsdcAccount: traderAccounts.usdcAccount.address,
+				})
+				.signers([trader])
+				.rpc();
+		const quoteReserveBefore = poolData.quoteReserve.toNumber();
+		const amountInAfterFee = Math.floor(
+			(amountIn.toNumber() * (10_000 - poolData.feeBps)) / 10_000
+		);
+		const expectedOut = Math.floor(
+			(amountInAfterFee * 5) / (quoteReserveBefore + amountInAfterFee)
+		);
+		assert.equal(expectedOut, 1);
+
+		try {
+			await swap(2);
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "SlippageExceeded");
+		}
+		await swap(1);
+
+		poolData = await program.account.pool.fetch(pool);
+		assert.equal(poolData.baseReserve.toNumber(), 4);
+		// The fee stays in the pool for the providers.
+		assert.equal(
+			poolData.quoteReserve.toNumber(),
+			quoteReserveBefore + amountIn.toNumber()
+		);
+		const traderTokens = await connection.getTokenAccountBalance(
+			traderAccounts.propertyTokenAccount.address
+		);
+		assert.equal(traderTokens.value.amount, "1");
+
+		// 1 USDC per token on the 4 pooled tokens, split by pool share.
+		await mintTo(
+			connection,
+			admin,
+			usdcMint,
+			adminUsdcAccount.address,
+			admin,
+			100 * 10 ** 6
+		);
+		await program.methods
+			.distributeDividends(new anchor.BN(100 * 10 ** 6))
+			.accountsPartial({
+				treasurer: admin.publicKey,
+				treasurerUsdcAccount: adminUsdcAccount.address,
+				property: propertyPda,
+			})
+			.signers([admin])
+			.rpc();
+
+		const claimPoolDividends = async (owner: Keypair) => {
+			const pendingBefore = (
+				await program.account.investor.fetch(findInvestment(owner.publicKey))
+			).dividendsPending.toNumber();
+			await program.methods
+				.claimPoolDividends()
+				.accountsPartial({
+					owner: owner.publicKey,
+					property: propertyPda,
+					pool,
+					lpPosition: findLpPosition(owner.publicKey),
+				})
+				.signers([owner])
+				.rpc();
+			return (
+				(
+					await program.account.investor.fetch(
+						findInvestment(owner.publicKey)
+					)
+				).dividendsPending.toNumber() - pendingBefore
+			);
+		};
+		assert.equal(await claimPoolDividends(lp), 3_200_000);
+		assert.equal(await claimPoolDividends(investor), 800_000);
+
+		// The second provider withdraws; the first keeps the pool open.
+		const lpShares = (
+			await program.account.lpPosition.fetch(findLpPosition(lp.publicKey))
+		).shares;
+		const removeLiquidity = (minBaseAmount: number) =>
+			program.methods
+				.removeLiquidity(
+					lpShares,
+					new anchor.BN(minBaseAmount),
+					new anchor.BN(0)
+				)
+				.accountsPartial({
+					payer: lp.publicKey,
+					provider: lp.publicKey,
+					property: propertyPda,
+					propertyMint,
+					pool,
+					providerInvestmentAccount: findInvestment(lp.publicKey),
+					lpPosition: findLpPosition(lp.publicKey),
+					providerPropertyTokenAccount: lpAccounts.propertyTokenAccount.address,
+					providerUsdcAccount: lpAccounts.usdcAccount.address,
+				})
+				.signers([lp])
+				.rpc();
+		try {
+			await removeLiquidity(4);
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "SlippageExceeded");
+		}
+		await removeLiquidity(3);
+
+		const lpPosition = await program.account.lpPosition.fetch(
+			findLpPosition(lp.publicKey)
+		);
+		assert.equal(lpPosition.shares.toNumber(), 0);
+		const lpInvestment = await program.account.investor.fetch(
+			findInvestment(lp.publicKey)
+		);
+		assert.equal(lpInvestment.tokensOwned.toNumber(), 3);
+		poolData = await program.account.pool.fetch(pool);
+		assert.equal(poolData.baseReserve.toNumber(), 1);
+		assert.isAbove(poolData.totalShares.toNumber(), 0);
+	});
+
+	it("Sells remaining tokens through a Dutch auction", async () => {
+		const bidder = Keypair.generate();
+		const latestBlockhash = await connection.getLatestBlockhash();
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				bidder.publicKey,
+				5 * anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+
+		const bidderUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			admin,
+			usdcMint,
+			bidder.publicKey
+		);
+		await mintTo(
+			connection,
+			admin,
+			usdcMint,
+			bidderUsdcAccount.address,
+			admin,
+			1_000 * 10 ** 6
+		);
+		const bidderPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				admin,
+				propertyMint,
+				bidder.publicKey
+			);
+
+		const [auctionBid] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("auction_bid"),
+				propertyPda.toBuffer(),
+				bidder.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		const now = await connection.getBlockTime(await connection.getSlot());
+		await program.methods
+			.configureDutchAuction(
+				new anchor.BN(200 * 10 ** 6),
+				new anchor.BN(100 * 10 ** 6),
+				new anchor.BN(now),
+				new anchor.BN(2)
+			)
+			.accountsPartial({
+				admin: admin.publicKey,
+				property: propertyPda,
+				usdcMint,
+			})
+			.signers([admin])
+			.rpc();
+
+		await program.methods
+			.placeAuctionBid(new anchor.BN(1), new anchor.BN(200 * 10 ** 6))
+			.accountsPartial({
+				bidder: bidder.publicKey,
+				property: propertyPda,
+				auctionBid,
+				usdcMint,
+				bidderUsdcAccount: bidderUsdcAccount.address,
+				propertyUsdcAccount: propertyUsdcVault.address,
+			})
+			.signers([bidder])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 3_000));
+
+		// Anyone can crank the settlement; the tokens and refund go to the bidder.
+		await program.methods
+			.settleAuctionBid()
+			.accountsPartial({
+				payer: admin.publicKey,
+				bidder: bidder.publicKey,
+				property: propertyPda,
+				auctionBid,
+				propertyMint,
+				propertyVault: propertyVault.address,
+				usdcMint,
+				propertyUsdcAccount: propertyUsdcVault.address,
+				bidderPropertyTokenAccount: bidderPropertyTokenAccount.address,
+				bidderUsdcAccount: bidderUsdcAccount.address,
+			})
+			.signers([admin])
+			.rpc();
+
+		const bidderUsdcBalance = await connection.getTokenAccountBalance(
+			bidderUsdcAccount.address
+		);
+		assert.equal(bidderUsdcBalance.value.amount, "900000000");
+
+		const propertyAccount = await program.account.property.fetch(
+			propertyPda
+		);
+		assert.deepEqual(propertyAccount.saleMode, { fixedPrice: {} });
+		assert.equal(
+			propertyAccount.tokenPriceUsdc.toNumber(),
+			100 * 10 ** 6
+		);
+	});
+
+	it("Closes a Dutch auction that nobody bid in", async () => {
+		const now = await connection.getBlockTime(await connection.getSlot());
+		await program.methods
+			.configureDutchAuction(
+				new anchor.BN(200 * 10 ** 6),
+				new anchor.BN(100 * 10 ** 6),
+				new anchor.BN(now),
+				new anchor.BN(2)
+			)
+			.accountsPartial({
+				admin: admin.publicKey,
+				property: propertyPda,
+				usdcMint,
+			})
+			.signers([admin])
+			.rpc();
+
+		try {
+			await program.methods
+				.closeAuction()
+				.accountsPartial({ property: propertyPda })
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "AuctionNotEnded");
+		}
+
+		await new Promise((resolve) => setTimeout(resolve, 4_000));
+
+		await program.methods
+			.closeAuction()
+			.accountsPartial({ property: propertyPda })
+			.rpc();
+
+		const propertyAccount = await program.account.property.fetch(
+			propertyPda
+		);
+		assert.deepEqual(propertyAccount.saleMode, { fixedPrice: {} });
+		assert.equal(
+			propertyAccount.tokenPriceUsdc.toNumber(),
+			100 * 10 ** 6
+		);
+	});
+
+	it("Allocates an oversubscribed commitment round", async () => {
+		const committer = Keypair.generate();
+		const latestBlockhash = await connection.getLatestBlockhash();
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				committer.publicKey,
+				5 * anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+
+		const available = (
+			await program.account.property.fetch(propertyPda)
+		).availableTokens.toNumber();
+
+		const committerUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			admin,
+			usdcMint,
+			committer.publicKey
+		);
+		await mintTo(
+			connection,
+			admin,
+			usdcMint,
+			committerUsdcAccount.address,
+			admin,
+			(available + 10) * 100 * 10 ** 6
+		);
+		const committerPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				admin,
+				propertyMint,
+				committer.publicKey
+			);
+
+		const [commitmentRound] = PublicKey.findProgramAddressSync(
+			[Buffer.from("commitment_round"), propertyPda.toBuffer()],
+			program.programId
+		);
+		const [commitment] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("commitment"),
+				propertyPda.toBuffer(),
+				committer.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		const now = await connection.getBlockTime(await connection.getSlot());
+		await program.methods
+			.openCommitmentRound(new anchor.BN(now + 2), [])
+			.accountsPartial({
+				admin: admin.publicKey,
+				payer: admin.publicKey,
+				usdcMint,
+				property: propertyPda,
+				commitmentRound,
+			})
+			.signers([admin])
+			.rpc();
+
+		try {
+			await program.methods
+				.commitToRound(new anchor.BN(available + 10))
+				.accountsPartial({
+					investor: committer.publicKey,
+					property: propertyPda,
+					commitmentRound,
+					commitment,
+					investorUsdcAccount: committerUsdcAccount.address,
+					usdcMint,
+					propertyUsdcAccount: committerUsdcAccount.address,
+				})
+				.signers([committer])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "ConstraintAssociated");
+		}
+
+		await program.methods
+			.commitToRound(new anchor.BN(available + 10))
+			.accountsPartial({
+				investor: committer.publicKey,
+				property: propertyPda,
+				commitmentRound,
+				commitment,
+				investorUsdcAccount: committerUsdcAccount.address,
+				usdcMint,
+				propertyUsdcAccount: propertyUsdcVault.address,
+			})
+			.signers([committer])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 3_000));
+
+		await program.methods
+			.finalizeCommitmentRound()
+			.accountsPartial({ property: propertyPda, commitmentRound })
+			.rpc();
+
+		// The fixed-price sale reopens at once; the allocation stays reserved.
+		let propertyAccount = await program.account.property.fetch(propertyPda);
+		assert.deepEqual(propertyAccount.saleMode, { fixedPrice: {} });
+		assert.equal(propertyAccount.availableTokens.toNumber(), 0);
+
+		// Anyone can crank the claim; the tokens and refund go to the committer.
+		await program.methods
+			.claimCommitment()
+			.accountsPartial({
+				payer: admin.publicKey,
+				investor: committer.publicKey,
+				property: propertyPda,
+				commitmentRound,
+				commitment,
+				propertyMint,
+				propertyVault: propertyVault.address,
+				usdcMint,
+				propertyUsdcAccount: propertyUsdcVault.address,
+				investorPropertyTokenAccount:
+					committerPropertyTokenAccount.address,
+				investorUsdcAccount: committerUsdcAccount.address,
+			})
+			.signers([admin])
+			.rpc();
+
+		const committerTokenBalance = await connection.getTokenAccountBalance(
+			committerPropertyTokenAccount.address
+		);
+		assert.equal(committerTokenBalance.value.amount, available.toString());
+
+		const committerUsdcBalance = await connection.getTokenAccountBalance(
+			committerUsdcAccount.address
+		);
+		assert.equal(committerUsdcBalance.value.amount, "1000000000");
+
+		propertyAccount = await program.account.property.fetch(propertyPda);
+		assert.equal(propertyAccount.availableTokens.toNumber(), 0);
+		assert.deepEqual(propertyAccount.saleMode, { fixedPrice: {} });
+	});
+
+	it("Refuses to close a property while its pool holds liquidity", async () => {
+		try {
+			await program.methods
+				.closeProperty()
+				.accountsPartial({
+					propertyMint,
+					propertyVault: propertyVault.address,
+					property: propertyPda,
+					operator: admin.publicKey,
+					admin: admin.publicKey,
+					adminUsdcAccount: adminUsdcAccount.address,
+				})
+				.signers([admin])
+				.rpc();
+			assert.fail("Closing with open escrows should fail");
+		} catch (error) {
+			assert.include(error.message, "EscrowsOpen");
+		}
+
+		const propertyAccount = await program.account.property.fetch(
+			propertyPda
+		);
+		assert.equal(propertyAccount.isClosed, false);
+	});
+});
+
+describe("Governance", async () => {
+	const provider = anchor.AnchorProvider.env();
+	anchor.setProvider(provider);
+	const connection = provider.connection;
+
+	const program = anchor.workspace.CrowdEstate as Program<CrowdEstate>;
+
+	let property: PublicKey;
+	let propertyMint: PublicKey;
+	let governance: PublicKey;
+	let proposal: PublicKey;
+	let bump: number;
+	let proposer = Keypair.generate();
+	let voter = Keypair.generate();
+	let voterInvestment: PublicKey;
+	let usdcMint: PublicKey;
+
+	before(async () => {
+		const latestBlockhash = await connection.getLatestBlockhash();
+
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				proposer.publicKey,
+				5 * anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				voter.publicKey,
+				5 * anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+
+		[property, bump] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("property"),
+				proposer.publicKey.toBuffer(),
+				Buffer.from("Test Property"),
+			],
+			program.programId
+		);
+
+		propertyMint = await createMint(
+			connection,
+			proposer,
+			property,
+			null,
+			0
+		);
+
+		[governance] = PublicKey.findProgramAddressSync(
+			[Buffer.from("governance"), property.toBuffer()],
+			program.programId
+		);
+
+		[proposal] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("proposal"),
+				property.toBuffer(),
+				new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		usdcMint = await createMint(
+			connection,
+			proposer,
+			proposer.publicKey,
+			null,
+			6
+		);
+
+		await program.methods
+			.createProperty(
+				"Test Property",
+				new anchor.BN(100),
+				new anchor.BN(100 * 10 ** 6),
+				"TST",
+				bump
+			)
+			.accountsPartial({
+				property: property,
+				admin: proposer.publicKey,
+				systemProgram: SystemProgram.programId,
+				propertyMint: propertyMint,
+				usdcMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.signers([proposer])
+			.rpc();
+
+		const propertyAccount = await program.account.property.fetch(property);
+		assert.include(
+			Buffer.from(propertyAccount.propertyName).toString(),
+			"Test Property"
+		);
+		assert.equal(Number(propertyAccount.totalTokens), 100);
+		assert.equal(Number(propertyAccount.availableTokens), 100);
+		assert.equal(propertyAccount.isClosed, false);
+
+		const voterUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			usdcMint,
+			voter.publicKey
+		);
+		await mintTo(
+			connection,
+			proposer,
+			usdcMint,
+			voterUsdcAccount.address,
+			proposer,
+			1_000 * 10 ** 6
+		);
+		const propertyUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			usdcMint,
+			property,
+			true
+		);
+		const propertyVault = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			propertyMint,
+			property,
+			true
+		);
+		const voterPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				proposer,
+				propertyMint,
+				voter.publicKey
+			);
+
+		[voterInvestment] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				voter.publicKey.toBuffer(),
+				property.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.investInProperty(
+				new anchor.BN(5),
+				new anchor.BN(100 * 10 ** 6),
+				new anchor.BN((await connection.getSlot()) + 100)
+			)
+			.accountsPartial({
+				usdcMint,
+				investor: voter.publicKey,
+				property,
+				investorUsdcAccount: voterUsdcAccount.address,
+				propertyMint,
+				investorPropertyTokenAccount: voterPropertyTokenAccount.address,
+				investmentAccount: voterInvestment,
+				investmentRecord: await findInvestmentRecordPda(program, property),
+				propertyUsdcAccount: propertyUsdcAccount.address,
+				propertyVault: propertyVault.address,
+			})
+			.signers([voter])
+			.rpc();
+	});
+
+	it("Creates a proposal", async () => {
+		await program.methods
+			.setGovernanceParams({
+				votingPeriod: new anchor.BN(3),
+				quorumBps: 500,
+				approvalThresholdBps: [
+					5_000, 6_667, 5_000, 5_000, 5_000, 5_000, 5_000, 5_000,
+				],
+				executionDelay: new anchor.BN(0),
+				gracePeriod: new anchor.BN(600),
+				minProposerTokens: new anchor.BN(0),
+				proposalDeposit: new anchor.BN(0),
+				depositAsset: { usdc: {} },
+			})
+			.accountsPartial({ admin: proposer.publicKey, property })
+			.signers([proposer])
+			.rpc();
+
+		await program.methods
+			.createProposal(
+				"Test Proposal",
+				{
+					mintAdditionalTokens: {
+						additionalTokens: new anchor.BN(100),
+					},
+				}
+			)
+			.accountsPartial({
+				investmentAccount: null,
+				governance,
+				proposal: proposal,
+				proposer: proposer.publicKey,
+				property: property,
+				systemProgram: SystemProgram.programId,
+			})
+			.signers([proposer])
+			.rpc();
+
+		const proposalAccount = await program.account.proposal.fetch(proposal);
+		assert.include(
+			Buffer.from(proposalAccount.description).toString(),
+			"Test Proposal"
+		);
+		assert.equal(Number(proposalAccount.votesFor), 0);
+		assert.equal(Number(proposalAccount.votesAgainst), 0);
+		assert.deepEqual(proposalAccount.status, { active: {} });
+		assert.equal(
+			proposalAccount.votingEnd.toNumber() -
+				proposalAccount.votingStart.toNumber(),
+			3
+		);
+		assert.equal(proposalAccount.index.toNumber(), 0);
+		assert.equal(
+			proposalAccount.expiresAt.toNumber() - proposalAccount.eta.toNumber(),
+			600
+		);
+
+		const governanceAccount = await program.account.governance.fetch(
+			governance
+		);
+		assert.equal(governanceAccount.proposalCount.toNumber(), 1);
+	});
+
+	it("Votes on a proposal", async () => {
+		const voteRecord = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("vote"),
+				proposal.toBuffer(),
+				voter.publicKey.toBuffer(),
+			],
+			program.programId
+		)[0];
+
+		await program.methods
+			.voteOnProposal(true)
+			.accountsPartial({
+				proposal: proposal,
+				voteRecord: voteRecord,
+				voter: voter.publicKey,
+				investmentAccount: voterInvestment,
+				systemProgram: SystemProgram.programId,
+			})
+			.signers([voter])
+			.rpc();
+
+		const proposalAccount = await program.account.proposal.fetch(proposal);
+		assert.equal(Number(proposalAccount.votesFor), 5);
+		assert.equal(Number(proposalAccount.votesAgainst), 0);
+
+		const voteRecordAccount = await program.account.voteRecord.fetch(
+			voteRecord
+		);
+		assert.equal(voteRecordAccount.weight.toNumber(), 5);
+	});
+
+	it("Rejects votes from wallets without property tokens", async () => {
+		const outsider = Keypair.generate();
+		const latestBlockhash = await connection.getLatestBlockhash();
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				outsider.publicKey,
+				anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+
+		const [outsiderInvestment] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				outsider.publicKey.toBuffer(),
+				property.toBuffer(),
+			],
+			program.programId
+		);
+
+		try {
+			await program.methods
+				.voteOnProposal(false)
+				.accountsPartial({
+					proposal,
+					voteRecord: PublicKey.findProgramAddressSync(
+						[
+							Buffer.from("vote"),
+							proposal.toBuffer(),
+							outsider.publicKey.toBuffer(),
+						],
+						program.programId
+					)[0],
+					voter: outsider.publicKey,
+					investmentAccount: outsiderInvestment,
+				})
+				.signers([outsider])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "AccountNotInitialized");
+		}
+	});
+
+	it("Executes a proposal", async () => {
+		await new Promise((resolve) => setTimeout(resolve, 4_000));
+
+		const destinationTokenAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			propertyMint,
+			proposer.publicKey
+		);
+
+		await program.methods
+			.executeProposal()
+			.accountsPartial({
+				admin: proposer.publicKey,
+				destinationTokenAccount: destinationTokenAccount.address,
+				property: property,
+				propertyMint: propertyMint,
+				proposal: proposal,
+				systemProgram: SystemProgram.programId,
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.signers([proposer])
+			.rpc();
+
+		const propertyAccount = await program.account.property.fetch(property);
+		assert.equal(propertyAccount.availableTokens.toNumber(), 195);
+		assert.equal(propertyAccount.totalTokens.toNumber(), 200);
+	});
+
+	it("Closes an executed proposal", async () => {
+		await program.methods
+			.closeProposal()
+			.accountsPartial({
+				investmentAccount: null,
+				proposer: proposer.publicKey,
+				property,
+				proposal,
+			})
+			.rpc();
+
+		assert.isNull(await connection.getAccountInfo(proposal));
+	});
+
+	it("Cancels a proposal before voting ends", async () => {
+		const [cancelledProposal] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("proposal"),
+				property.toBuffer(),
+				new anchor.BN(1).toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.createProposal(
+				"Mistaken Proposal",
+				{ changeTokenPrice: { tokenPriceUsdc: new anchor.BN(1) } }
+			)
+			.accountsPartial({
+				investmentAccount: null,
+				governance,
+				proposal: cancelledProposal,
+				proposer: proposer.publicKey,
+				property,
+			})
+			.signers([proposer])
+			.rpc();
+
+		await program.methods
+			.cancelProposal()
+			.accountsPartial({
+				proposer: proposer.publicKey,
+				proposal: cancelledProposal,
+			})
+			.signers([proposer])
+			.rpc();
+
+		const proposalAccount = await program.account.proposal.fetch(
+			cancelledProposal
+		);
+		assert.deepEqual(proposalAccount.status, { cancelled: {} });
+
+		await program.methods
+			.closeProposal()
+			.accountsPartial({
+				investmentAccount: null,
+				proposer: proposer.publicKey,
+				property,
+				proposal: cancelledProposal,
+			})
+			.rpc();
+
+		assert.isNull(await connection.getAccountInfo(cancelledProposal));
+	});
+
+	it("Rejects custom proposals that call back into the program", async () => {
+		const [nextProposal] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("proposal"),
+				property.toBuffer(),
+				new anchor.BN(2).toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		try {
+			await program.methods
+				.createProposal("Self call", {
+					custom: {
+						instructions: [
+							{
+								programId: program.programId,
+								accounts: [],
+								data: Buffer.from([]),
+							},
+						],
+					},
+				})
+				.accountsPartial({
+					investmentAccount: null,
+					governance,
+					proposal: nextProposal,
+					proposer: proposer.publicKey,
+					property,
+				})
+				.signers([proposer])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "InvalidGovernedInstruction");
+		}
+	});
+
+	it("Casts delegated voting power", async () => {
+		const [delegatedProposal] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("proposal"),
+				property.toBuffer(),
+				new anchor.BN(2).toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+		const [delegation] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("delegation"),
+				property.toBuffer(),
+				voter.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+		const [delegatorVoteRecord] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("vote"),
+				delegatedProposal.toBuffer(),
+				voter.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.delegateVotes(proposer.publicKey)
+			.accountsPartial({
+				delegator: voter.publicKey,
+				property,
+				delegation,
+			})
+			.signers([voter])
+			.rpc();
+
+		await program.methods
+			.setGovernanceParams({
+				votingPeriod: new anchor.BN(60),
+				quorumBps: 500,
+				approvalThresholdBps: [
+					5_000, 6_667, 5_000, 5_000, 5_000, 5_000, 5_000, 5_000,
+				],
+				executionDelay: new anchor.BN(0),
+				gracePeriod: new anchor.BN(600),
+				minProposerTokens: new anchor.BN(0),
+				proposalDeposit: new anchor.BN(0),
+				depositAsset: { usdc: {} },
+			})
+			.accountsPartial({ admin: proposer.publicKey, property })
+			.signers([proposer])
+			.rpc();
+
+		await program.methods
+			.createProposal("Delegated Proposal", {
+				changeTokenPrice: { tokenPriceUsdc: new anchor.BN(200 * 10 ** 6) },
+			})
+			.accountsPartial({
+				investmentAccount: null,
+				governance,
+				proposal: delegatedProposal,
+				proposer: proposer.publicKey,
+				property,
+			})
+			.signers([proposer])
+			.rpc();
+
+		// A funded address must not stop the delegate from creating the record.
+		const latestBlockhash = await connection.getLatestBlockhash();
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(delegatorVoteRecord, 1_000),
+			...latestBlockhash,
+		});
+
+		await program.methods
+			.voteOnProposal(true)
+			.accountsPartial({
+				proposal: delegatedProposal,
+				voteRecord: PublicKey.findProgramAddressSync(
+					[
+						Buffer.from("vote"),
+						delegatedProposal.toBuffer(),
+						proposer.publicKey.toBuffer(),
+					],
+					program.programId
+				)[0],
+				voter: proposer.publicKey,
+				investmentAccount: null,
+			})
+			.remainingAccounts([
+				{ pubkey: delegation, isSigner: false, isWritable: false },
+				{ pubkey: voterInvestment, isSigner: false, isWritable: false },
+				{ pubkey: delegatorVoteRecord, isSigner: false, isWritable: true },
+			])
+			.signers([proposer])
+			.rpc();
+
+		const proposalAccount = await program.account.proposal.fetch(
+			delegatedProposal
+		);
+		assert.equal(proposalAccount.votesFor.toNumber(), 5);
+
+		const delegatorVote = await program.account.voteRecord.fetch(
+			delegatorVoteRecord
+		);
+		assert.equal(delegatorVote.weight.toNumber(), 5);
+		assert.equal(
+			delegatorVote.castBy.toBase58(),
+			proposer.publicKey.toBase58()
+		);
+	});
+
+	it("Lets a delegator override and retract a delegated vote", async () => {
+		const [delegatedProposal] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("proposal"),
+				property.toBuffer(),
+				new anchor.BN(2).toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+		const [delegatorVoteRecord] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("vote"),
+				delegatedProposal.toBuffer(),
+				voter.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.changeVote(false)
+			.accountsPartial({
+				authority: voter.publicKey,
+				proposal: delegatedProposal,
+				voteRecord: delegatorVoteRecord,
+			})
+			.signers([voter])
+			.rpc();
+
+		let proposalAccount = await program.account.proposal.fetch(
+			delegatedProposal
+		);
+		assert.equal(proposalAccount.votesFor.toNumber(), 0);
+		assert.equal(proposalAccount.votesAgainst.toNumber(), 5);
+
+		const delegatorVote = await program.account.voteRecord.fetch(
+			delegatorVoteRecord
+		);
+		assert.deepEqual(delegatorVote.choice, { against: {} });
+		assert.equal(
+			delegatorVote.castBy.toBase58(),
+			voter.publicKey.toBase58()
+		);
+
+		await program.methods
+			.retractVote()
+			.accountsPartial({
+				authority: voter.publicKey,
+				proposal: delegatedProposal,
+				voteRecord: delegatorVoteRecord,
+			})
+			.signers([voter])
+			.rpc();
+
+		proposalAccount = await program.account.proposal.fetch(
+			delegatedProposal
+		);
+		assert.equal(proposalAccount.votesAgainst.toNumber(), 0);
+	});
+
+	it("Ignores tokens received after the proposal snapshot", async () => {
+		const [delegatedProposal] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("proposal"),
+				property.toBuffer(),
+				new anchor.BN(2).toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+		const recipient = Keypair.generate();
+		const latestBlockhash = await connection.getLatestBlockhash();
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				recipient.publicKey,
+				anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+
+		const voterPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				proposer,
+				propertyMint,
+				voter.publicKey
+			);
+		const recipientPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				proposer,
+				propertyMint,
+				recipient.publicKey
+			);
+		const [recipientInvestment] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				recipient.publicKey.toBuffer(),
+				property.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.transferTokens(new anchor.BN(2))
+			.accountsPartial({
+				authority: voter.publicKey,
+				fromTokenAccount: voterPropertyTokenAccount.address,
+				toTokenAccount: recipientPropertyTokenAccount.address,
+				to: recipient.publicKey,
+				propertyMint,
+				property,
+				fromInvestmentAccount: voterInvestment,
+				toInvestmentAccount: recipientInvestment,
+			})
+			.signers([voter])
+			.rpc();
+
+		try {
+			await program.methods
+				.voteOnProposal(true)
+				.accountsPartial({
+					proposal: delegatedProposal,
+					voteRecord: PublicKey.findProgramAddressSync(
+						[
+							Buffer.from("vote"),
+							delegatedProposal.toBuffer(),
+							recipient.publicKey.toBuffer(),
+						],
+						program.programId
+					)[0],
+					voter: recipient.publicKey,
+					investmentAccount: recipientInvestment,
+				})
+				.signers([recipient])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "NoVotingPower");
+		}
+	});
+
+	it("Forfeits the deposit of a proposal that misses quorum", async () => {
+		await program.methods
+			.setGovernanceParams({
+				votingPeriod: new anchor.BN(1),
+				quorumBps: 500,
+				approvalThresholdBps: [
+					5_000, 6_667, 5_000, 5_000, 5_000, 5_000, 5_000, 5_000,
+				],
+				executionDelay: new anchor.BN(0),
+				gracePeriod: new anchor.BN(600),
+				minProposerTokens: new anchor.BN(1),
+				proposalDeposit: new anchor.BN(10 * 10 ** 6),
+				depositAsset: { usdc: {} },
+			})
+			.accountsPartial({ admin: proposer.publicKey, property })
+			.signers([proposer])
+			.rpc();
+
+		const governanceAccount = await program.account.governance.fetch(
+			governance
+		);
+		const [spamProposal] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("proposal"),
+				property.toBuffer(),
+				governanceAccount.proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		try {
+			await program.methods
+				.createProposal("Unbacked Proposal", {
+					changeTokenPrice: { tokenPriceUsdc: new anchor.BN(1) },
+				})
+				.accountsPartial({
+					investmentAccount: null,
+					governance,
+					proposal: spamProposal,
+					proposer: proposer.publicKey,
+					property,
+				})
+				.signers([proposer])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "InsufficientProposerTokens");
+		}
+
+		const voterUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			usdcMint,
+			voter.publicKey
+		);
+		const propertyUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			usdcMint,
+			property,
+			true
+		);
+		const balanceBefore = Number(voterUsdcAccount.amount);
+
+		const sideUsdcAccount = await createAccount(
+			connection,
+			proposer,
+			usdcMint,
+			property,
+			Keypair.generate()
+		);
+		try {
+			await program.methods
+				.createProposal("Spam Proposal", {
+					changeTokenPrice: { tokenPriceUsdc: new anchor.BN(1) },
+				})
+				.accountsPartial({
+					investmentAccount: voterInvestment,
+					governance,
+					proposal: spamProposal,
+					proposer: voter.publicKey,
+					property,
+					proposerDepositAccount: voterUsdcAccount.address,
+					propertyDepositAccount: sideUsdcAccount,
+				})
+				.signers([voter])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "InvalidDepositAccount");
+		}
+
+		await program.methods
+			.createProposal("Spam Proposal", {
+				changeTokenPrice: { tokenPriceUsdc: new anchor.BN(1) },
+			})
+			.accountsPartial({
+				investmentAccount: voterInvestment,
+				governance,
+				proposal: spamProposal,
+				proposer: voter.publicKey,
+				property,
+				proposerDepositAccount: voterUsdcAccount.address,
+				propertyDepositAccount: propertyUsdcAccount.address,
+			})
+			.signers([voter])
+			.rpc();
+
+		let propertyAccount = await program.account.property.fetch(property);
+		assert.equal(
+			propertyAccount.proposalDepositsHeld.toNumber(),
+			10 * 10 ** 6
+		);
+
+		await new Promise((resolve) => setTimeout(resolve, 2000));
+
+		await program.methods
+			.closeProposal()
+			.accountsPartial({
+				investmentAccount: voterInvestment,
+				proposer: voter.publicKey,
+				property,
+				proposal: spamProposal,
+				proposerDepositAccount: voterUsdcAccount.address,
+				propertyDepositAccount: propertyUsdcAccount.address,
+			})
+			.rpc();
+
+		propertyAccount = await program.account.property.fetch(property);
+		assert.equal(propertyAccount.proposalDepositsHeld.toNumber(), 0);
+		const voterUsdc = await getAccount(connection, voterUsdcAccount.address);
+		assert.equal(Number(voterUsdc.amount), balanceBefore - 10 * 10 ** 6);
+	});
+
+	it("Keeps snapshot voting power through a burst of incoming transfers", async () => {
+		await program.methods
+			.setGovernanceParams({
+				votingPeriod: new anchor.BN(60),
+				quorumBps: 0,
+				approvalThresholdBps: [
+					5_000, 6_667, 5_000, 5_000, 5_000, 5_000, 5_000, 5_000,
+				],
+				executionDelay: new anchor.BN(0),
+				gracePeriod: new anchor.BN(600),
+				minProposerTokens: new anchor.BN(0),
+				proposalDeposit: new anchor.BN(0),
+				depositAsset: { usdc: {} },
+			})
+			.accountsPartial({ admin: proposer.publicKey, property })
+			.signers([proposer])
+			.rpc();
+
+		const attacker = Keypair.generate();
+		const latestBlockhash = await connection.getLatestBlockhash();
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				attacker.publicKey,
+				5 * anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+		const attackerUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			usdcMint,
+			attacker.publicKey
+		);
+		await mintTo(
+			connection,
+			proposer,
+			usdcMint,
+			attackerUsdcAccount.address,
+			proposer,
+			10_000 * 10 ** 6
+		);
+		const attackerPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				proposer,
+				propertyMint,
+				attacker.publicKey
+			);
+		const [attackerInvestment] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				attacker.publicKey.toBuffer(),
+				property.toBuffer(),
+			],
+			program.programId
+		);
+		const propertyAccount = await program.account.property.fetch(property);
+		await program.methods
+			.investInProperty(
+				new anchor.BN(9),
+				propertyAccount.tokenPriceUsdc,
+				new anchor.BN((await connection.getSlot()) + 100)
+			)
+			.accountsPartial({
+				usdcMint,
+				investor: attacker.publicKey,
+				property,
+				investorUsdcAccount: attackerUsdcAccount.address,
+				propertyMint,
+				investorPropertyTokenAccount: attackerPropertyTokenAccount.address,
+				investmentAccount: attackerInvestment,
+				investmentRecord: await findInvestmentRecordPda(program, property),
+				propertyUsdcAccount: (
+					await getOrCreateAssociatedTokenAccount(
+						connection,
+						proposer,
+						usdcMint,
+						property,
+						true
+					)
+				).address,
+				propertyVault: (
+					await getOrCreateAssociatedTokenAccount(
+						connection,
+						proposer,
+						propertyMint,
+						property,
+						true
+					)
+				).address,
+			})
+			.signers([attacker])
+			.rpc();
+
+		const voterPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				proposer,
+				propertyMint,
+				voter.publicKey
+			);
+		const transferToVoter = () =>
+			program.methods
+				.transferTokens(new anchor.BN(1))
+				.accountsPartial({
+					authority: attacker.publicKey,
+					fromTokenAccount: attackerPropertyTokenAccount.address,
+					toTokenAccount: voterPropertyTokenAccount.address,
+					to: voter.publicKey,
+					propertyMint,
+					property,
+					fromInvestmentAccount: attackerInvestment,
+					toInvestmentAccount: voterInvestment,
+				})
+				.signers([attacker])
+				.rpc();
+		// The balance the proposal snapshots is the voter's latest checkpoint,
+		// which the burst below must not merge away.
+		await transferToVoter();
+		await new Promise((resolve) => setTimeout(resolve, 500));
+
+		const snapshotBalance = (
+			await program.account.investor.fetch(voterInvestment)
+		).tokensOwned.toNumber();
+
+		const governanceAccount = await program.account.governance.fetch(
+			governance
+		);
+		const [griefedProposal] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("proposal"),
+				property.toBuffer(),
+				governanceAccount.proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+		await program.methods
+			.createProposal("Griefed Proposal", {
+				changeTokenPrice: { tokenPriceUsdc: new anchor.BN(1) },
+			})
+			.accountsPartial({
+				investmentAccount: null,
+				governance,
+				proposal: griefedProposal,
+				proposer: proposer.publicKey,
+				property,
+			})
+			.signers([proposer])
+			.rpc();
+
+		// One checkpoint per slot: enough transfers to fill every slot the
+		// voter's position keeps.
+		for (let i = 0; i < 8; i++) {
+			await transferToVoter();
+			await new Promise((resolve) => setTimeout(resolve, 500));
+		}
+
+		const voteRecord = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("vote"),
+				griefedProposal.toBuffer(),
+				voter.publicKey.toBuffer(),
+			],
+			program.programId
+		)[0];
+		await program.methods
+			.voteOnProposal(true)
+			.accountsPartial({
+				proposal: griefedProposal,
+				voteRecord,
+				voter: voter.publicKey,
+				investmentAccount: voterInvestment,
+			})
+			.signers([voter])
+			.rpc();
+
+		const voteRecordAccount = await program.account.voteRecord.fetch(
+			voteRecord
+		);
+		assert.equal(voteRecordAccount.weight.toNumber(), snapshotBalance);
+	});
+
+	it("Judges proposals by the quorum and threshold they were created under", async () => {
+		const setParams = (quorumBps: number, changePriceThresholdBps: number) =>
+			program.methods
+				.setGovernanceParams({
+					votingPeriod: new anchor.BN(4),
+					quorumBps,
+					approvalThresholdBps: [
+						5_000,
+						6_667,
+						changePriceThresholdBps,
+						5_000,
+						5_000,
+						5_000,
+						5_000,
+						5_000,
+					],
+					executionDelay: new anchor.BN(0),
+					gracePeriod: new anchor.BN(600),
+					minProposerTokens: new anchor.BN(0),
+					proposalDeposit: new anchor.BN(0),
+					depositAsset: { usdc: {} },
+				})
+				.accountsPartial({ admin: proposer.publicKey, property })
+				.signers([proposer])
+				.rpc();
+		const proposeAndVote = async (description: string) => {
+			const governanceAccount = await program.account.governance.fetch(
+				governance
+			);
+			const [newProposal] = PublicKey.findProgramAddressSync(
+				[
+					Buffer.from("proposal"),
+					property.toBuffer(),
+					governanceAccount.proposalCount.toArrayLike(Buffer, "le", 8),
+				],
+				program.programId
+			);
+			await program.methods
+				.createProposal(description, {
+					changeTokenPrice: { tokenPriceUsdc: new anchor.BN(1) },
+				})
+				.accountsPartial({
+					investmentAccount: null,
+					governance,
+					proposal: newProposal,
+					proposer: proposer.publicKey,
+					property,
+				})
+				.signers([proposer])
+				.rpc();
+			await program.methods
+				.voteOnProposal(true)
+				.accountsPartial({
+					proposal: newProposal,
+					voteRecord: PublicKey.findProgramAddressSync(
+						[
+							Buffer.from("vote"),
+							newProposal.toBuffer(),
+							voter.publicKey.toBuffer(),
+						],
+						program.programId
+					)[0],
+					voter: voter.publicKey,
+					investmentAccount: voterInvestment,
+				})
+				.signers([voter])
+				.rpc();
+			return newProposal;
+		};
+
+		// Give the proposer a token to dissent with.
+		const voterPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				proposer,
+				propertyMint,
+				voter.publicKey
+			);
+		const proposerPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				proposer,
+				propertyMint,
+				proposer.publicKey
+			);
+		const [proposerInvestment] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment"),
+				proposer.publicKey.toBuffer(),
+				property.toBuffer(),
+			],
+			program.programId
+		);
+		await program.methods
+			.transferTokens(new anchor.BN(1))
+			.accountsPartial({
+				authority: voter.publicKey,
+				fromTokenAccount: voterPropertyTokenAccount.address,
+				toTokenAccount: proposerPropertyTokenAccount.address,
+				to: proposer.publicKey,
+				propertyMint,
+				property,
+				fromInvestmentAccount: voterInvestment,
+				toInvestmentAccount: proposerInvestment,
+			})
+			.signers([voter])
+			.rpc();
+
+		// Every token must vote for quorum, which the voter alone cannot reach.
+		await setParams(10_000, 5_000);
+		const missesQuorum = await proposeAndVote("Misses Quorum");
+
+		// A 100% threshold needs every vote cast to be in favour.
+		await setParams(0, 10_000);
+		const missesThreshold = await proposeAndVote("Misses Threshold");
+		await program.methods
+			.voteOnProposal(false)
+			.accountsPartial({
+				proposal: missesThreshold,
+				voteRecord: PublicKey.findProgramAddressSync(
+					[
+						Buffer.from("vote"),
+						missesThreshold.toBuffer(),
+						proposer.publicKey.toBuffer(),
+					],
+					program.programId
+				)[0],
+				voter: proposer.publicKey,
+				investmentAccount: proposerInvestment,
+			})
+			.signers([proposer])
+			.rpc();
+		const unanimous = await proposeAndVote("Unanimous");
+
+		// Loosening the rules mid-vote must not rescue either proposal.
+		await setParams(0, 5_000);
+
+		await new Promise((resolve) => setTimeout(resolve, 5_000));
+
+		for (const finalized of [missesQuorum, missesThreshold, unanimous]) {
+			await program.methods
+				.finalizeProposal()
+				.accountsPartial({ property, proposal: finalized })
+				.rpc();
+		}
+
+		const quorumProposal = await program.account.proposal.fetch(
+			missesQuorum
+		);
+		assert.deepEqual(quorumProposal.status, { defeated: {} });
+		assert.equal(quorumProposal.quorumBps, 10_000);
+		assert.equal(Number(quorumProposal.votesAgainst), 0);
+
+		const thresholdProposal = await program.account.proposal.fetch(
+			missesThreshold
+		);
+		assert.deepEqual(thresholdProposal.status, { defeated: {} });
+		assert.equal(thresholdProposal.approvalThresholdBps, 10_000);
+		assert.equal(Number(thresholdProposal.votesAgainst), 1);
+
+		const unanimousProposal = await program.account.proposal.fetch(
+			unanimous
+		);
+		assert.deepEqual(unanimousProposal.status, { succeeded: {} });
+	});
+
+	it("Executes each built-in proposal payload", async () => {
+		await program.methods
+			.setGovernanceParams({
+				votingPeriod: new anchor.BN(3),
+				quorumBps: 0,
+				approvalThresholdBps: [
+					5_000, 6_667, 5_000, 5_000, 5_000, 5_000, 5_000, 5_000,
+				],
+				executionDelay: new anchor.BN(0),
+				gracePeriod: new anchor.BN(600),
+				minProposerTokens: new anchor.BN(0),
+				proposalDeposit: new anchor.BN(0),
+				depositAsset: { usdc: {} },
+			})
+			.accountsPartial({ admin: proposer.publicKey, property })
+			.signers([proposer])
+			.rpc();
+
+		const propertyUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			usdcMint,
+			property,
+			true
+		);
+		const recipientUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			usdcMint,
+			proposer.publicKey
+		);
+		const destinationTokenAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			propertyMint,
+			proposer.publicKey
+		);
+
+		const payloads = [
+			{ changeTokenPrice: { tokenPriceUsdc: new anchor.BN(150 * 10 ** 6) } },
+			{
+				updateFeeParameters: {
+					platformFeeBps: 250,
+					referralShareBps: 1_000,
+					maxTokensPerInvestor: new anchor.BN(50),
+				},
+			},
+			{ updateMetadataUri: { metadataUri: "https://example.com/property.json" } },
+			{
+				releaseReserveFunds: {
+					amount: new anchor.BN(10 * 10 ** 6),
+					recipient: recipientUsdcAccount.address,
+				},
+			},
+			// Last, since it puts the property into liquidation.
+			{ approveSale: { liquidationPriceUsdc: new anchor.BN(120 * 10 ** 6) } },
+		];
+
+		const proposals: PublicKey[] = [];
+		for (const payload of payloads) {
+			const governanceAccount = await program.account.governance.fetch(
+				governance
+			);
+			const [newProposal] = PublicKey.findProgramAddressSync(
+				[
+					Buffer.from("proposal"),
+					property.toBuffer(),
+					governanceAccount.proposalCount.toArrayLike(Buffer, "le", 8),
+				],
+				program.programId
+			);
+			await program.methods
+				.createProposal(Object.keys(payload)[0], payload)
+				.accountsPartial({
+					investmentAccount: null,
+					governance,
+					proposal: newProposal,
+					proposer: proposer.publicKey,
+					property,
+				})
+				.signers([proposer])
+				.rpc();
+			await program.methods
+				.voteOnProposal(true)
+				.accountsPartial({
+					proposal: newProposal,
+					voteRecord: PublicKey.findProgramAddressSync(
+						[
+							Buffer.from("vote"),
+							newProposal.toBuffer(),
+							voter.publicKey.toBuffer(),
+						],
+						program.programId
+					)[0],
+					voter: voter.publicKey,
+					investmentAccount: voterInvestment,
+				})
+				.signers([voter])
+				.rpc();
+			proposals.push(newProposal);
+		}
+
+		// A listing left open when the sale is approved.
+		const voterPropertyTokenAccount =
+			await getOrCreateAssociatedTokenAccount(
+				connection,
+				proposer,
+				propertyMint,
+				voter.publicKey
+			);
+		const [listing] = PublicKey.findProgramAddressSync(
+			[Buffer.from("listing"), property.toBuffer(), voter.publicKey.toBuffer()],
+			program.programId
+		);
+		const createListing = () =>
+			program.methods
+				.createListing(new anchor.BN(1), new anchor.BN(150 * 10 ** 6))
+				.accountsPartial({
+					seller: voter.publicKey,
+					property,
+					propertyMint,
+					sellerInvestmentAccount: voterInvestment,
+					sellerPropertyTokenAccount: voterPropertyTokenAccount.address,
+					usdcMint,
+					listing,
+				})
+				.signers([voter])
+				.rpc();
+		const cancelListingAsProposer = () =>
+			program.methods
+				.cancelListing()
+				.accountsPartial({
+					payer: proposer.publicKey,
+					seller: voter.publicKey,
+					property,
+					propertyMint,
+					listing,
+					sellerInvestmentAccount: voterInvestment,
+					sellerPropertyTokenAccount: voterPropertyTokenAccount.address,
+				})
+				.signers([proposer])
+				.rpc();
+		await createListing();
+		try {
+			await cancelListingAsProposer();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "Unauthorized");
+		}
+
+		await new Promise((resolve) => setTimeout(resolve, 4_000));
+
+		const usdcBefore = await connection.getTokenAccountBalance(
+			recipientUsdcAccount.address
+		);
+
+		for (const executed of proposals) {
+			await program.methods
+				.executeProposal()
+				.accountsPartial({
+					admin: proposer.publicKey,
+					property,
+					propertyMint,
+					proposal: executed,
+					destinationTokenAccount: destinationTokenAccount.address,
+					propertyUsdcAccount: propertyUsdcAccount.address,
+					recipientUsdcAccount: recipientUsdcAccount.address,
+				})
+				.signers([proposer])
+				.rpc();
+		}
+
+		const propertyAccount = await program.account.property.fetch(property);
+		assert.equal(propertyAccount.tokenPriceUsdc.toNumber(), 150 * 10 ** 6);
+		assert.equal(propertyAccount.platformFeeBps, 250);
+		assert.equal(propertyAccount.referralShareBps, 1_000);
+		assert.equal(propertyAccount.maxTokensPerInvestor.toNumber(), 50);
+		assert.equal(
+			propertyAccount.metadataUri,
+			"https://example.com/property.json"
+		);
+		assert.isTrue(propertyAccount.isLiquidating);
+		assert.equal(
+			propertyAccount.liquidationPriceUsdc.toNumber(),
+			120 * 10 ** 6
+		);
+
+		const usdcAfter = await connection.getTokenAccountBalance(
+			recipientUsdcAccount.address
+		);
+		assert.equal(
+			Number(usdcAfter.value.amount) - Number(usdcBefore.value.amount),
+			10 * 10 ** 6
+		);
+
+		// Once liquidating, anyone can unwind the listing so it cannot keep the
+		// property from closing, and no new escrow can be opened.
+		await cancelListingAsProposer();
+		assert.isNull(await connection.getAccountInfo(listing));
+		assert.equal(
+			(
+				await program.account.investor.fetch(voterInvestment)
+			).tokensListed.toNumber(),
+			0
+		);
+		try {
+			await createListing();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "PropertyLiquidating");
+		}
+	});
+
+	it("Executes proposals only between their eta and grace deadline", async () => {
+		const setTimelock = (executionDelay: number, gracePeriod: number) =>
+			program.methods
+				.setGovernanceParams({
+					votingPeriod: new anchor.BN(3),
+					quorumBps: 0,
+					approvalThresholdBps: [
+						5_000, 6_667, 5_000, 5_000, 5_000, 5_000, 5_000, 5_000,
+					],
+					executionDelay: new anchor.BN(executionDelay),
+					gracePeriod: new anchor.BN(gracePeriod),
+					minProposerTokens: new anchor.BN(0),
+					proposalDeposit: new anchor.BN(0),
+					depositAsset: { usdc: {} },
+				})
+				.accountsPartial({ admin: proposer.publicKey, property })
+				.signers([proposer])
+				.rpc();
+		const proposeAndVote = async (description: string) => {
+			const governanceAccount = await program.account.governance.fetch(
+				governance
+			);
+			const [newProposal] = PublicKey.findProgramAddressSync(
+				[
+					Buffer.from("proposal"),
+					property.toBuffer(),
+					governanceAccount.proposalCount.toArrayLike(Buffer, "le", 8),
+				],
+				program.programId
+			);
+			await program.methods
+				.createProposal(description, {
+					changeTokenPrice: { tokenPriceUsdc: new anchor.BN(1) },
+				})
+				.accountsPartial({
+					investmentAccount: null,
+					governance,
+					proposal: newProposal,
+					proposer: proposer.publicKey,
+					property,
+				})
+				.signers([proposer])
+				.rpc();
+			await program.methods
+				.voteOnProposal(true)
+				.accountsPartial({
+					proposal: newProposal,
+					voteRecord: PublicKey.findProgramAddressSync(
+						[
+							Buffer.from("vote"),
+							newProposal.toBuffer(),
+							voter.publicKey.toBuffer(),
+						],
+						program.programId
+					)[0],
+					voter: voter.publicKey,
+					investmentAccount: voterInvestment,
+				})
+				.signers([voter])
+				.rpc();
+			return newProposal;
+		};
+		const destinationTokenAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			propertyMint,
+			proposer.publicKey
+		);
+		const execute = (executed: PublicKey) =>
+			program.methods
+				.executeProposal()
+				.accountsPartial({
+					admin: proposer.publicKey,
+					property,
+					propertyMint,
+					proposal: executed,
+					destinationTokenAccount: destinationTokenAccount.address,
+					propertyUsdcAccount: null,
+					recipientUsdcAccount: null,
+				})
+				.signers([proposer])
+				.rpc();
+
+		await setTimelock(600, 600);
+		const timelocked = await proposeAndVote("Timelocked");
+
+		await setTimelock(0, 1);
+		const lapsing = await proposeAndVote("Lapsing");
+
+		await new Promise((resolve) => setTimeout(resolve, 6_000));
+
+		try {
+			await execute(timelocked);
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "TimelockNotElapsed");
+		}
+
+		try {
+			await execute(lapsing);
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "ProposalExpired");
+		}
+
+		const propertyAccount = await program.account.property.fetch(property);
+		assert.equal(propertyAccount.tokenPriceUsdc.toNumber(), 150 * 10 ** 6);
+	});
+
+	it("Executes a custom proposal signed by the property", async () => {
+		await program.methods
+			.setGovernanceParams({
+				votingPeriod: new anchor.BN(3),
+				quorumBps: 0,
+				approvalThresholdBps: [
+					5_000, 6_667, 5_000, 5_000, 5_000, 5_000, 5_000, 5_000,
+				],
+				executionDelay: new anchor.BN(0),
+				gracePeriod: new anchor.BN(600),
+				minProposerTokens: new anchor.BN(0),
+				proposalDeposit: new anchor.BN(0),
+				depositAsset: { usdc: {} },
+			})
+			.accountsPartial({ admin: proposer.publicKey, property })
+			.signers([proposer])
+			.rpc();
+
+		const propertyUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			usdcMint,
+			property,
+			true
+		);
+		const recipientUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			usdcMint,
+			proposer.publicKey
+		);
+		const destinationTokenAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			propertyMint,
+			proposer.publicKey
+		);
+
+		// An SPL transfer out of the property's USDC account, authorised by the property PDA.
+		const transfer = createTransferInstruction(
+			propertyUsdcAccount.address,
+			recipientUsdcAccount.address,
+			property,
+			1 * 10 ** 6
+		);
+
+		const governanceAccount = await program.account.governance.fetch(
+			governance
+		);
+		const [customProposal] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("proposal"),
+				property.toBuffer(),
+				governanceAccount.proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+		await program.methods
+			.createProposal("Custom transfer", {
+				custom: {
+					instructions: [
+						{
+							programId: transfer.programId,
+							accounts: transfer.keys,
+							data: transfer.data,
+						},
+					],
+				},
+			})
+			.accountsPartial({
+				investmentAccount: null,
+				governance,
+				proposal: customProposal,
+				proposer: proposer.publicKey,
+				property,
+			})
+			.signers([proposer])
+			.rpc();
+		await program.methods
+			.voteOnProposal(true)
+			.accountsPartial({
+				proposal: customProposal,
+				voteRecord: PublicKey.findProgramAddressSync(
+					[
+						Buffer.from("vote"),
+						customProposal.toBuffer(),
+						voter.publicKey.toBuffer(),
+					],
+					program.programId
+				)[0],
+				voter: voter.publicKey,
+				investmentAccount: voterInvestment,
+			})
+			.signers([voter])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 4_000));
+
+		const usdcBefore = await connection.getTokenAccountBalance(
+			recipientUsdcAccount.address
+		);
+
+		await program.methods
+			.executeProposal()
+			.accountsPartial({
+				admin: proposer.publicKey,
+				property,
+				propertyMint,
+				proposal: customProposal,
+				destinationTokenAccount: destinationTokenAccount.address,
+				propertyUsdcAccount: propertyUsdcAccount.address,
+				recipientUsdcAccount: null,
+			})
+			.remainingAccounts([
+				{
+					pubkey: propertyUsdcAccount.address,
+					isSigner: false,
+					isWritable: true,
+				},
+				{
+					pubkey: recipientUsdcAccount.address,
+					isSigner: false,
+					isWritable: true,
+				},
+				{ pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+			])
+			.signers([proposer])
+			.rpc();
+
+		const usdcAfter = await connection.getTokenAccountBalance(
+			recipientUsdcAccount.address
+		);
+		assert.equal(
+			Number(usdcAfter.value.amount) - Number(usdcBefore.value.amount),
+			1 * 10 ** 6
+		);
+
+		const proposalAccount = await program.account.proposal.fetch(
+			customProposal
+		);
+		assert.deepEqual(proposalAccount.status, { executed: {} });
+	});
+
+	it("Rejects custom proposals that hand a property account to someone else", async () => {
+		const propertyUsdcAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			usdcMint,
+			property,
+			true
+		);
+		const destinationTokenAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			propertyMint,
+			proposer.publicKey
+		);
+
+		const setAuthority = createSetAuthorityInstruction(
+			propertyUsdcAccount.address,
+			property,
+			AuthorityType.AccountOwner,
+			proposer.publicKey
+		);
+
+		const governanceAccount = await program.account.governance.fetch(
+			governance
+		);
+		const [customProposal] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("proposal"),
+				property.toBuffer(),
+				governanceAccount.proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+		await program.methods
+			.createProposal("Hand over the USDC account", {
+				custom: {
+					instructions: [
+						{
+							programId: setAuthority.programId,
+							accounts: setAuthority.keys,
+							data: setAuthority.data,
+						},
+					],
+				},
+			})
+			.accountsPartial({
+				investmentAccount: null,
+				governance,
+				proposal: customProposal,
+				proposer: proposer.publicKey,
+				property,
+			})
+			.signers([proposer])
+			.rpc();
+		await program.methods
+			.voteOnProposal(true)
+			.accountsPartial({
+				proposal: customProposal,
+				voteRecord: PublicKey.findProgramAddressSync(
+					[
+						Buffer.from("vote"),
+						customProposal.toBuffer(),
+						voter.publicKey.toBuffer(),
+					],
+					program.programId
+				)[0],
+				voter: voter.publicKey,
+				investmentAccount: voterInvestment,
+			})
+			.signers([voter])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 4_000));
+
+		try {
+			await program.methods
+				.executeProposal()
+				.accountsPartial({
+					admin: proposer.publicKey,
+					property,
+					propertyMint,
+					proposal: customProposal,
+					destinationTokenAccount: destinationTokenAccount.address,
+					propertyUsdcAccount: propertyUsdcAccount.address,
+					recipientUsdcAccount: null,
+				})
+				.remainingAccounts([
+					{
+						pubkey: propertyUsdcAccount.address,
+						isSigner: false,
+						isWritable: true,
+					},
+					{ pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+				])
+				.signers([proposer])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "InvalidGovernedInstruction");
+		}
+
+		const usdcAccount = await getAccount(
+			connection,
+			propertyUsdcAccount.address
+		);
+		assert.isTrue(usdcAccount.owner.equals(property));
+	});
+
+	it("Lets only the guardian veto a passed proposal", async () => {
+		await program.methods
+			.setGovernanceParams({
+				votingPeriod: new anchor.BN(3),
+				quorumBps: 0,
+				approvalThresholdBps: [
+					5_000, 6_667, 5_000, 5_000, 5_000, 5_000, 5_000, 5_000,
+				],
+				executionDelay: new anchor.BN(0),
+				gracePeriod: new anchor.BN(600),
+				minProposerTokens: new anchor.BN(0),
+				proposalDeposit: new anchor.BN(0),
+				depositAsset: { usdc: {} },
+			})
+			.accountsPartial({ admin: proposer.publicKey, property })
+			.signers([proposer])
+			.rpc();
+
+		const proposeAndVote = async (description: string) => {
+			const governanceAccount = await program.account.governance.fetch(
+				governance
+			);
+			const [newProposal] = PublicKey.findProgramAddressSync(
+				[
+					Buffer.from("proposal"),
+					property.toBuffer(),
+					governanceAccount.proposalCount.toArrayLike(Buffer, "le", 8),
+				],
+				program.programId
+			);
+			await program.methods
+				.createProposal(description, {
+					changeTokenPrice: { tokenPriceUsdc: new anchor.BN(1) },
+				})
+				.accountsPartial({
+					investmentAccount: null,
+					governance,
+					proposal: newProposal,
+					proposer: proposer.publicKey,
+					property,
+				})
+				.signers([proposer])
+				.rpc();
+			await program.methods
+				.voteOnProposal(true)
+				.accountsPartial({
+					proposal: newProposal,
+					voteRecord: PublicKey.findProgramAddressSync(
+						[
+							Buffer.from("vote"),
+							newProposal.toBuffer(),
+							voter.publicKey.toBuffer(),
+						],
+						program.programId
+					)[0],
+					voter: voter.publicKey,
+					investmentAccount: voterInvestment,
+				})
+				.signers([voter])
+				.rpc();
+			return newProposal;
+		};
+		const reasonHash = Array.from(Buffer.alloc(32, 7));
 
-	it("Votes on a proposal", async () => {
-		const voteRecord = PublicKey.findProgramAddressSync(
+		const succeeded = await proposeAndVote("Vetoed after finalizing");
+		const unfinalized = await proposeAndVote("Vetoed before finalizing");
+
+		await new Promise((resolve) => setTimeout(resolve, 4_000));
+
+		await program.methods
+			.finalizeProposal()
+			.accountsPartial({ property, proposal: succeeded })
+			.rpc();
+		assert.deepEqual(
+			(await program.account.proposal.fetch(succeeded)).status,
+			{ succeeded: {} }
+		);
+
+		// The proposer holds the guardian role; the voter does not.
+		try {
+			await program.methods
+				.vetoProposal(reasonHash)
+				.accountsPartial({
+					guardian: voter.publicKey,
+					property,
+					proposal: succeeded,
+				})
+				.signers([voter])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "ConstraintHasOne");
+		}
+
+		for (const vetoed of [succeeded, unfinalized]) {
+			await program.methods
+				.vetoProposal(reasonHash)
+				.accountsPartial({
+					guardian: proposer.publicKey,
+					property,
+					proposal: vetoed,
+				})
+				.signers([proposer])
+				.rpc();
+
+			const proposalAccount = await program.account.proposal.fetch(vetoed);
+			assert.deepEqual(proposalAccount.status, { vetoed: {} });
+			assert.deepEqual(proposalAccount.vetoReasonHash, reasonHash);
+		}
+
+		const destinationTokenAccount = await getOrCreateAssociatedTokenAccount(
+			connection,
+			proposer,
+			propertyMint,
+			proposer.publicKey
+		);
+		try {
+			await program.methods
+				.executeProposal()
+				.accountsPartial({
+					admin: proposer.publicKey,
+					property,
+					propertyMint,
+					proposal: succeeded,
+					destinationTokenAccount: destinationTokenAccount.address,
+					propertyUsdcAccount: null,
+					recipientUsdcAccount: null,
+				})
+				.signers([proposer])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "ProposalNotApproved");
+		}
+	});
+
+	it("Hands the admin role over only once it is accepted", async () => {
+		await program.methods
+			.transferAdmin(voter.publicKey)
+			.accountsPartial({ admin: proposer.publicKey, property })
+			.signers([proposer])
+			.rpc();
+
+		let propertyAccount = await program.account.property.fetch(property);
+		assert.equal(
+			propertyAccount.admin.toBase58(),
+			proposer.publicKey.toBase58()
+		);
+		assert.equal(
+			propertyAccount.pendingAdmin.toBase58(),
+			voter.publicKey.toBase58()
+		);
+
+		const outsider = Keypair.generate();
+		try {
+			await program.methods
+				.acceptAdmin()
+				.accountsPartial({ newAdmin: outsider.publicKey, property })
+				.signers([outsider])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "InvalidNewAdmin");
+		}
+
+		await program.methods
+			.acceptAdmin()
+			.accountsPartial({ newAdmin: voter.publicKey, property })
+			.signers([voter])
+			.rpc();
+
+		propertyAccount = await program.account.property.fetch(property);
+		assert.equal(propertyAccount.admin.toBase58(), voter.publicKey.toBase58());
+		assert.isNull(propertyAccount.pendingAdmin);
+
+		// The roles the old admin held move with the admin role.
+		const [rolesPda] = PublicKey.findProgramAddressSync(
+			[Buffer.from("roles"), property.toBuffer()],
+			program.programId
+		);
+		const roles = await program.account.roleAssignment.fetch(rolesPda);
+		for (const holder of [
+			roles.operator,
+			roles.treasurer,
+			roles.compliance,
+			roles.guardian,
+		]) {
+			assert.equal(holder.toBase58(), voter.publicKey.toBase58());
+		}
+	});
+});
+
+describe("Admin council", async () => {
+	const provider = anchor.AnchorProvider.env();
+	anchor.setProvider(provider);
+	const connection = provider.connection;
+
+	const program = anchor.workspace.CrowdEstate as Program<CrowdEstate>;
+
+	const founder = Keypair.generate();
+	const partner = Keypair.generate();
+	const outsider = Keypair.generate();
+	let property: PublicKey;
+	let propertyMint: PublicKey;
+	let usdcMint: PublicKey;
+	let council: PublicKey;
+	let roles: PublicKey;
+
+	const findCouncilAction = (index: number) =>
+		PublicKey.findProgramAddressSync(
 			[
-				Buffer.from("vote"),
-				proposal.toBuffer(),
-				voter.publicKey.toBuffer(),
+				Buffer.from("council_action"),
+				council.toBuffer(),
+				new anchor.BN(index).toArrayLike(Buffer, "le", 8),
 			],
 			program.programId
 		)[0];
 
+	// Accounts of an admin instruction for `executeCouncilAction`; the council
+	// signs through the program, so only the executing member signs here.
+	const councilAccounts = (
+		instruction: anchor.web3.TransactionInstruction,
+		executor: PublicKey
+	) =>
+		instruction.keys.map((key) => ({
+			...key,
+			isSigner: key.pubkey.equals(executor),
+		}));
+
+	const runCouncilAction = async (
+		action,
+		instruction: anchor.web3.TransactionInstruction,
+		proposer: Keypair,
+		approver: Keypair | null
+	) => {
+		const { actionCount } = await program.account.adminCouncil.fetch(council);
+		const councilAction = findCouncilAction(actionCount.toNumber());
+
 		await program.methods
-			.voteOnProposal(true)
+			.proposeCouncilAction(action)
+			.accountsPartial({ member: proposer.publicKey, council, councilAction })
+			.signers([proposer])
+			.rpc();
+		if (approver) {
+			await program.methods
+				.approveCouncilAction()
+				.accountsPartial({
+					member: approver.publicKey,
+					council,
+					councilAction,
+				})
+				.signers([approver])
+				.rpc();
+		}
+		await program.methods
+			.executeCouncilAction()
+			.accountsPartial({ member: proposer.publicKey, council, councilAction })
+			.remainingAccounts(councilAccounts(instruction, proposer.publicKey))
+			.signers([proposer])
+			.rpc();
+	};
+
+	before(async () => {
+		const latestBlockhash = await connection.getLatestBlockhash();
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				partner.publicKey,
+				5 * anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+		await connection.confirmTransaction({
+			signature: await connection.requestAirdrop(
+				founder.publicKey,
+				5 * anchor.web3.LAMPORTS_PER_SOL
+			),
+			...latestBlockhash,
+		});
+
+		let bump: number;
+		[property, bump] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("property"),
+				founder.publicKey.toBuffer(),
+				Buffer.from("Council Property"),
+			],
+			program.programId
+		);
+		[council] = PublicKey.findProgramAddressSync(
+			[Buffer.from("admin_council"), property.toBuffer()],
+			program.programId
+		);
+		[roles] = PublicKey.findProgramAddressSync(
+			[Buffer.from("roles"), property.toBuffer()],
+			program.programId
+		);
+
+		propertyMint = await createMint(
+			connection,
+			founder,
+			property,
+			null,
+			0
+		);
+		usdcMint = await createMint(
+			connection,
+			founder,
+			founder.publicKey,
+			null,
+			6
+		);
+
+		await program.methods
+			.createProperty(
+				"Council Property",
+				new anchor.BN(100),
+				new anchor.BN(100 * 10 ** 6),
+				"CNL",
+				bump
+			)
 			.accountsPartial({
-				proposal: proposal,
-				voteRecord: voteRecord,
-				voter: voter.publicKey,
-				systemProgram: SystemProgram.programId,
+				property,
+				admin: founder.publicKey,
+				propertyMint,
+				usdcMint,
 			})
-			.signers([voter])
+			.signers([founder])
 			.rpc();
 
-		const proposalAccount = await program.account.proposal.fetch(proposal);
-		assert.equal(Number(proposalAccount.votesFor), 1);
-		assert.equal(Number(proposalAccount.votesAgainst), 0);
+		await program.methods
+			.createAdminCouncil(
+				[founder.publicKey, partner.publicKey, outsider.publicKey],
+				2
+			)
+			.accountsPartial({ admin: founder.publicKey, property, council })
+			.signers([founder])
+			.rpc();
+
+		const propertyAccount = await program.account.property.fetch(property);
+		assert.equal(propertyAccount.admin.toBase58(), council.toBase58());
+		const rolesAccount = await program.account.roleAssignment.fetch(roles);
+		assert.equal(rolesAccount.operator.toBase58(), council.toBase58());
+		assert.equal(rolesAccount.guardian.toBase58(), council.toBase58());
+		// Fees and withheld tax keep going to a wallet, not the council PDA.
+		assert.equal(
+			propertyAccount.feeRecipient.toBase58(),
+			founder.publicKey.toBase58()
+		);
+		assert.equal(
+			propertyAccount.taxRecipient.toBase58(),
+			founder.publicKey.toBase58()
+		);
 	});
 
-	it("Executes a proposal", async () => {
-		const destinationTokenAccount = await getOrCreateAssociatedTokenAccount(
-			connection,
-			proposer,
-			propertyMint,
-			proposer.publicKey
+	it("Runs an admin action once the threshold approves it", async () => {
+		const councilAction = findCouncilAction(0);
+
+		await program.methods
+			.proposeCouncilAction({ updateProperty: { tokenSymbol: "CNCL" } })
+			.accountsPartial({
+				member: founder.publicKey,
+				council,
+				councilAction,
+			})
+			.signers([founder])
+			.rpc();
+
+		const updateAccounts = [
+			{ pubkey: council, isSigner: false, isWritable: true },
+			{ pubkey: property, isSigner: false, isWritable: true },
+			{ pubkey: roles, isSigner: false, isWritable: false },
+			{ pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+			{
+				pubkey: ASSOCIATED_TOKEN_PROGRAM_ID,
+				isSigner: false,
+				isWritable: false,
+			},
+		];
+
+		try {
+			await program.methods
+				.executeCouncilAction()
+				.accountsPartial({
+					member: founder.publicKey,
+					council,
+					councilAction,
+				})
+				.remainingAccounts(updateAccounts)
+				.signers([founder])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "ThresholdNotMet");
+		}
+
+		await program.methods
+			.approveCouncilAction()
+			.accountsPartial({
+				member: partner.publicKey,
+				council,
+				councilAction,
+			})
+			.signers([partner])
+			.rpc();
+
+		await program.methods
+			.executeCouncilAction()
+			.accountsPartial({
+				member: partner.publicKey,
+				council,
+				councilAction,
+			})
+			.remainingAccounts(updateAccounts)
+			.signers([partner])
+			.rpc();
+
+		const propertyAccount = await program.account.property.fetch(property);
+		assert.equal(
+			Buffer.from(propertyAccount.tokenSymbol).toString(),
+			"CNCL"
 		);
+		const actionAccount = await program.account.councilAction.fetch(
+			councilAction
+		);
+		assert.isTrue(actionAccount.executed);
+	});
+
+	it("Stops former admins from acting alone", async () => {
+		try {
+			await program.methods
+				.updateProperty("SOLO")
+				.accountsPartial({
+					operator: founder.publicKey,
+					property,
+					associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+				})
+				.signers([founder])
+				.rpc();
+			assert.fail("Expected an error but did not get one");
+		} catch (error) {
+			assert.include(error.message, "ConstraintHasOne");
+		}
+	});
+
+	it("Hands a role out through the council", async () => {
+		const councilAction = findCouncilAction(1);
 
 		await program.methods
-			.executeProposal()
+			.proposeCouncilAction({
+				setRole: { role: { treasurer: {} }, holder: partner.publicKey },
+			})
 			.accountsPartial({
-				admin: proposer.publicKey,
-				destinationTokenAccount: destinationTokenAccount.address,
-				newAdmin: proposer.publicKey,
-				property: property,
-				propertyMint: propertyMint,
-				proposal: proposal,
-				systemProgram: SystemProgram.programId,
-				tokenProgram: TOKEN_PROGRAM_ID,
+				member: founder.publicKey,
+				council,
+				councilAction,
 			})
-			.signers([proposer])
+			.signers([founder])
+			.rpc();
+		await program.methods
+			.approveCouncilAction()
+			.accountsPartial({
+				member: outsider.publicKey,
+				council,
+				councilAction,
+			})
+			.signers([outsider])
+			.rpc();
+		await program.methods
+			.executeCouncilAction()
+			.accountsPartial({
+				member: founder.publicKey,
+				council,
+				councilAction,
+			})
+			.remainingAccounts([
+				{ pubkey: council, isSigner: false, isWritable: false },
+				{ pubkey: property, isSigner: false, isWritable: false },
+				{ pubkey: roles, isSigner: false, isWritable: true },
+			])
+			.signers([founder])
 			.rpc();
 
+		const rolesAccount = await program.account.roleAssignment.fetch(roles);
+		assert.equal(
+			rolesAccount.treasurer.toBase58(),
+			partner.publicKey.toBase58()
+		);
+		assert.equal(rolesAccount.operator.toBase58(), council.toBase58());
+	});
+
+	it("Runs admin-only setup through the council", async () => {
+		await runCouncilAction(
+			{ setPlatformFee: { platformFeeBps: 100, referralShareBps: 0 } },
+			await program.methods
+				.setPlatformFee(100, 0)
+				.accountsPartial({ admin: council, property })
+				.instruction(),
+			founder,
+			partner
+		);
+
+		const [pool] = PublicKey.findProgramAddressSync(
+			[Buffer.from("pool"), property.toBuffer()],
+			program.programId
+		);
+		await runCouncilAction(
+			{ initializePool: { feeBps: 30 } },
+			await program.methods
+				.initializePool(30)
+				.accountsPartial({
+					admin: council,
+					payer: partner.publicKey,
+					property,
+					propertyMint,
+					usdcMint,
+					pool,
+				})
+				.instruction(),
+			partner,
+			founder
+		);
+
+		await runCouncilAction(
+			{
+				setFeeRecipients: {
+					feeRecipient: partner.publicKey,
+					taxRecipient: outsider.publicKey,
+				},
+			},
+			await program.methods
+				.setFeeRecipients(partner.publicKey, outsider.publicKey)
+				.accountsPartial({ admin: council, property })
+				.instruction(),
+			founder,
+			partner
+		);
+
 		const propertyAccount = await program.account.property.fetch(property);
-		assert.equal(propertyAccount.availableTokens.toNumber(), 200);
-		assert.equal(propertyAccount.totalTokens.toNumber(), 200);
+		assert.equal(propertyAccount.platformFeeBps, 100);
+		assert.equal(
+			propertyAccount.feeRecipient.toBase58(),
+			partner.publicKey.toBase58()
+		);
+		assert.equal(
+			propertyAccount.taxRecipient.toBase58(),
+			outsider.publicKey.toBase58()
+		);
+		const poolAccount = await program.account.pool.fetch(pool);
+		assert.equal(poolAccount.quoteMint.toBase58(), usdcMint.toBase58());
+	});
+
+	it("Changes the council's members and threshold", async () => {
+		await runCouncilAction(
+			{
+				updateCouncil: {
+					members: [founder.publicKey, partner.publicKey],
+					threshold: 1,
+				},
+			},
+			await program.methods
+				.updateAdminCouncil([founder.publicKey, partner.publicKey], 1)
+				.accountsPartial({ council })
+				.instruction(),
+			founder,
+			outsider
+		);
+
+		const councilAccount = await program.account.adminCouncil.fetch(council);
+		assert.equal(councilAccount.threshold, 1);
+		assert.equal(councilAccount.members.length, 2);
+
+		// A single member now clears the threshold on their own.
+		await runCouncilAction(
+			{ setPlatformFee: { platformFeeBps: 0, referralShareBps: 0 } },
+			await program.methods
+				.setPlatformFee(0, 0)
+				.accountsPartial({ admin: council, property })
+				.instruction(),
+			founder,
+			null
+		);
+
+		const propertyAccount = await program.account.property.fetch(property);
+		assert.equal(propertyAccount.platformFeeBps, 0);
 	});
 });
@@ -176,3 +176,23 @@ export async function setupAccounts(
 		investorPropertyTokenAccount,
 	};
 }
+
+/**
+ * Derives the record PDA the next `investInProperty` call on a property will create.
+ */
+export async function findInvestmentRecordPda(
+	program: anchor.Program<CrowdEstate>,
+	propertyPda: PublicKey
+) {
+	const propertyAccount = await program.account.property.fetch(propertyPda);
+	const [investmentRecord] = PublicKey.findProgramAddressSync(
+		[
+			Buffer.from("investment_record"),
+			propertyPda.toBuffer(),
+			propertyAccount.investmentCount.toArrayLike(Buffer, "le", 8),
+		],
+		program.programId
+	);
+
+	return investmentRecord;
+}